@@ -0,0 +1,71 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iced::widget::{scrollable, text};
+use iced::{Element, Renderer, Theme};
+use iced_table::table;
+
+const ROWS: usize = 1_000;
+const COLUMNS: usize = 20;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Synced,
+    Resizing,
+    Resized,
+}
+
+struct Column {
+    index: usize,
+}
+
+impl<'a> table::Column<'a, Message, Theme, Renderer> for Column {
+    type Row = Vec<String>;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message> {
+        text(self.index).into()
+    }
+
+    fn cell(
+        &'a self,
+        col_index: usize,
+        _row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message> {
+        text(&row[col_index]).into()
+    }
+
+    fn width(&self) -> f32 {
+        120.0
+    }
+
+    fn resize_offset(&self) -> Option<f32> {
+        None
+    }
+}
+
+fn view(c: &mut Criterion) {
+    let header = scrollable::Id::unique();
+    let body = scrollable::Id::unique();
+
+    let columns: Vec<_> = (0..COLUMNS).map(|index| Column { index }).collect();
+    let rows: Vec<Vec<String>> = (0..ROWS)
+        .map(|row| {
+            (0..COLUMNS)
+                .map(|column| format!("{row}:{column}"))
+                .collect()
+        })
+        .collect();
+
+    c.bench_function("view 1000x20", |b| {
+        b.iter(|| {
+            let table = table(header.clone(), body.clone(), &columns, &rows, |_| {
+                Message::Synced
+            })
+            .on_column_resize(|_, _| Message::Resizing, Message::Resized);
+
+            black_box(Element::<'_, Message>::from(table))
+        })
+    });
+}
+
+criterion_group!(benches, view);
+criterion_main!(benches);
@@ -1,12 +1,13 @@
 use std::fmt;
-use std::collections::HashMap;
+use std::path::PathBuf;
 
 use iced::widget::{
     button, checkbox, column, container, horizontal_space, pick_list, responsive, scrollable, text,
     text_input,
 };
 use iced::{Element, Length, Renderer, Task, Theme};
-use iced_table::{table, ColumnVisibilityMessage};
+use iced_table::table::Column as _;
+use iced_table::{table, ColumnVisibilityMessage, Columns};
 
 fn main() {
     iced::application(App::new, App::update, App::view)
@@ -21,7 +22,9 @@ enum Message {
     SyncHeader(scrollable::AbsoluteOffset),
     Resizing(usize, f32),
     Resized,
+    Reordered(usize, usize),
     ResizeColumnsEnabled(bool),
+    ReorderColumnsEnabled(bool),
     FooterEnabled(bool),
     MinWidthEnabled(bool),
     DarkThemeEnabled(bool),
@@ -30,49 +33,43 @@ enum Message {
     Category(usize, Category),
     Enabled(usize, bool),
     Delete(usize),
+    FilesDropped(Vec<PathBuf>, Option<usize>),
     ColumnVisibility(ColumnVisibilityMessage),
 }
 
 struct App {
-    columns: Vec<Column>,
+    columns: Columns<Column>,
     rows: Vec<Row>,
     header: scrollable::Id,
     body: scrollable::Id,
     footer: scrollable::Id,
     resize_columns_enabled: bool,
+    reorder_columns_enabled: bool,
     footer_enabled: bool,
     min_width_enabled: bool,
     column_visibility_enabled: bool,
-    column_visibility: HashMap<String, bool>,
     theme: Theme,
 }
 
 impl Default for App {
     fn default() -> Self {
-        let mut column_visibility = HashMap::new();
-        column_visibility.insert("index".to_string(), true);
-        column_visibility.insert("category".to_string(), true);
-        column_visibility.insert("enabled".to_string(), true);
-        column_visibility.insert("notes".to_string(), true);
-        column_visibility.insert("delete".to_string(), false); // Hidden by default
-
         Self {
-            columns: vec![
+            columns: Columns::new([
                 Column::new(ColumnKind::Index),
                 Column::new(ColumnKind::Category),
                 Column::new(ColumnKind::Enabled),
                 Column::new(ColumnKind::Notes),
                 Column::new(ColumnKind::Delete),
-            ],
+            ]),
             rows: (0..50).map(Row::generate).collect(),
             header: scrollable::Id::unique(),
             body: scrollable::Id::unique(),
             footer: scrollable::Id::unique(),
             resize_columns_enabled: true,
+            reorder_columns_enabled: true,
             footer_enabled: true,
             min_width_enabled: true,
             column_visibility_enabled: true,
-            column_visibility,
             theme: Theme::Light,
         }
     }
@@ -93,23 +90,13 @@ impl App {
 
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::SyncHeader(offset) => {
-                return Task::batch(vec![
-                    scrollable::scroll_to(self.header.clone(), offset),
-                    scrollable::scroll_to(self.footer.clone(), offset),
-                ])
-            }
-            Message::Resizing(index, offset) => {
-                if let Some(column) = self.columns.get_mut(index) {
-                    column.resize_offset = Some(offset);
-                }
-            }
-            Message::Resized => self.columns.iter_mut().for_each(|column| {
-                if let Some(offset) = column.resize_offset.take() {
-                    column.width += offset;
-                }
-            }),
+            // Header & footer are kept in sync by the table itself
+            Message::SyncHeader(_offset) => {}
+            Message::Resizing(index, offset) => self.columns.resize(index, offset),
+            Message::Resized => self.columns.apply_resize(),
+            Message::Reordered(from, to) => self.columns.reorder(from, to),
             Message::ResizeColumnsEnabled(enabled) => self.resize_columns_enabled = enabled,
+            Message::ReorderColumnsEnabled(enabled) => self.reorder_columns_enabled = enabled,
             Message::FooterEnabled(enabled) => self.footer_enabled = enabled,
             Message::MinWidthEnabled(enabled) => self.min_width_enabled = enabled,
             Message::ColumnVisibilityEnabled(enabled) => self.column_visibility_enabled = enabled,
@@ -138,24 +125,19 @@ impl App {
             Message::Delete(index) => {
                 self.rows.remove(index);
             }
-            Message::ColumnVisibility(visibility_msg) => {
-                match visibility_msg {
-                    ColumnVisibilityMessage::ToggleColumn(column_id) => {
-                        if let Some(visible) = self.column_visibility.get_mut(&column_id) {
-                            *visible = !*visible;
-                            
-                            // Update the corresponding column
-                            if let Some(column) = self.columns.iter_mut().find(|c| c.id() == column_id) {
-                                column.visible = *visible;
-                            }
-                        }
-                    }
-                    ColumnVisibilityMessage::HideContextMenu => {
-                        // Context menu was closed, no action needed
-                        // This could be used to do cleanup if needed
-                    }
+            Message::FilesDropped(paths, Some(index)) => {
+                if let Some(row) = self.rows.get_mut(index) {
+                    let names = paths
+                        .iter()
+                        .filter_map(|path| path.file_name())
+                        .map(|name| name.to_string_lossy())
+                        .collect::<Vec<_>>();
+
+                    row.notes = names.join(", ");
                 }
             }
+            Message::FilesDropped(_, None) => {}
+            Message::ColumnVisibility(message) => self.columns.update_visibility(message),
         }
 
         Task::none()
@@ -169,11 +151,15 @@ impl App {
                 &self.columns,
                 &self.rows,
                 Message::SyncHeader,
-            );
+            )
+            .on_file_drop(Message::FilesDropped);
 
             if self.resize_columns_enabled {
                 table = table.on_column_resize(Message::Resizing, Message::Resized);
             }
+            if self.reorder_columns_enabled {
+                table = table.on_column_reorder(Message::Reordered);
+            }
             if self.footer_enabled {
                 table = table.footer(self.footer.clone());
             }
@@ -187,12 +173,14 @@ impl App {
             table.into()
         });
 
-        let visible_columns_count = self.columns.iter().filter(|c| c.visible).count();
+        let visible_columns_count = self.columns.iter().filter(|c| c.is_visible()).count();
 
         let content = column![
             text("Table Features:").size(16),
             checkbox("Resize Columns", self.resize_columns_enabled,)
                 .on_toggle(Message::ResizeColumnsEnabled),
+            checkbox("Reorder Columns (Drag headers)", self.reorder_columns_enabled,)
+                .on_toggle(Message::ReorderColumnsEnabled),
             checkbox("Footer", self.footer_enabled,).on_toggle(Message::FooterEnabled),
             checkbox("Min Width", self.min_width_enabled,).on_toggle(Message::MinWidthEnabled),
             checkbox("Column Visibility (Right-click headers)", self.column_visibility_enabled,)
@@ -220,7 +208,6 @@ impl App {
 struct Column {
     kind: ColumnKind,
     width: f32,
-    resize_offset: Option<f32>,
     visible: bool,
 }
 
@@ -242,7 +229,6 @@ impl Column {
         Self {
             kind,
             width,
-            resize_offset: None,
             visible,
         }
     }
@@ -375,11 +361,11 @@ impl<'a> table::Column<'a, Message, Theme, Renderer> for Column {
     }
 
     fn resize_offset(&self) -> Option<f32> {
-        self.resize_offset
+        None
     }
 
     // Implement the new trait methods for column visibility
-    fn id(&self) -> String {
+    fn id(&self, _col_index: usize) -> String {
         Column::id(self).to_string()
     }
 
@@ -390,4 +376,8 @@ impl<'a> table::Column<'a, Message, Theme, Renderer> for Column {
     fn is_visible(&self) -> bool {
         self.visible
     }
+
+    fn resizable(&self) -> bool {
+        !matches!(self.kind, ColumnKind::Index)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,842 @@
+use std::cell::Cell;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::operation::{self, Operation};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{clipboard, renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::time::{Duration, Instant};
+use iced::widget::container;
+use iced::{
+    event, keyboard, mouse, overlay, touch, window, Background, Color, Element, Length, Rectangle,
+    Size, Vector,
+};
+
+use crate::budget::Budget;
+use crate::debug;
+use crate::import;
+use crate::style;
+use crate::sync::ScrollTo;
+
+const FLASH_DURATION: Duration = Duration::from_millis(1200);
+const ZOOM_STEP: f32 = 1.1;
+pub(crate) const MIN_SCALE: f32 = 0.25;
+pub(crate) const MAX_SCALE: f32 = 4.0;
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    dropped_files: Vec<PathBuf>,
+    drop_row: Option<usize>,
+    // Vertical offset & height of each row, relative to the top of the body
+    pub(crate) rows: Vec<(f32, f32)>,
+    // The row currently emphasized, e.g. by the find bar
+    pub(crate) highlight: Option<usize>,
+    // The visible position of the cells emphasized within the highlighted row
+    pub(crate) highlight_cells: Vec<usize>,
+    flash: Option<Flash>,
+    visible_rows: Option<Range<usize>>,
+    modifiers: keyboard::Modifiers,
+    // Whether visible cells are waiting for the render budget of the next frame
+    is_budget_pending: bool,
+    // Whether the rows were clicked last, rather than something else of the window
+    is_focused: bool,
+    // The row & visible position of the cell moved by keyboard navigation
+    pub(crate) focus: Option<(usize, usize)>,
+    // The number of cells of each row, not counting the filler
+    columns: usize,
+}
+
+/// Shared between the [`Body`] & the widgets outside of it reading the row of its
+/// focused cell, like the overflow menu of the header.
+///
+/// The body refreshes it on every view & event it receives.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FocusedRow(Rc<Cell<Option<usize>>>);
+
+impl FocusedRow {
+    pub fn get(&self) -> Option<usize> {
+        self.0.get()
+    }
+
+    fn set(&self, row: Option<usize>) {
+        self.0.set(row);
+    }
+}
+
+#[derive(Debug)]
+struct Flash {
+    row: usize,
+    // Set on the first frame after the flash was requested
+    start: Option<Instant>,
+    progress: f32,
+}
+
+/// Wraps the column of rows inside the body scrollable, giving the table
+/// access to the geometry of each row.
+pub(crate) struct Body<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_file_drop: Option<fn(Vec<PathBuf>, Option<usize>) -> Message>,
+    on_visible_rows: Option<fn(Range<usize>) -> Message>,
+    on_paste_rows: Option<fn(Vec<Vec<String>>) -> Message>,
+    on_row_toggle: Option<fn(usize, bool) -> Message>,
+    on_zoom: Option<fn(f32) -> Message>,
+    scale: f32,
+    reduce_motion: bool,
+    budget: Option<Budget>,
+    focused_row: FocusedRow,
+    // How many widgets each row wraps its cells in, e.g. a hover overlay or a resize handle
+    nested_cells: usize,
+    columns: usize,
+    debug: bool,
+    style: <Theme as style::Catalog>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Body<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        style: <Theme as style::Catalog>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_file_drop: None,
+            on_visible_rows: None,
+            on_paste_rows: None,
+            on_row_toggle: None,
+            on_zoom: None,
+            scale: 1.0,
+            reduce_motion: false,
+            budget: None,
+            focused_row: FocusedRow::default(),
+            nested_cells: 0,
+            columns: 0,
+            debug: false,
+            style,
+        }
+    }
+
+    pub fn nested_cells(mut self, nested_cells: usize) -> Self {
+        self.nested_cells = nested_cells;
+        self
+    }
+
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    pub fn reduce_motion(mut self, reduce_motion: bool) -> Self {
+        self.reduce_motion = reduce_motion;
+        self
+    }
+
+    pub fn render_budget(mut self, budget: Option<Budget>) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    pub fn focused_row(mut self, focused_row: FocusedRow) -> Self {
+        self.focused_row = focused_row;
+        self
+    }
+
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    pub fn on_file_drop(
+        mut self,
+        on_file_drop: Option<fn(Vec<PathBuf>, Option<usize>) -> Message>,
+    ) -> Self {
+        self.on_file_drop = on_file_drop;
+        self
+    }
+
+    pub fn on_visible_rows(mut self, on_visible_rows: Option<fn(Range<usize>) -> Message>) -> Self {
+        self.on_visible_rows = on_visible_rows;
+        self
+    }
+
+    pub fn on_paste_rows(mut self, on_paste_rows: Option<fn(Vec<Vec<String>>) -> Message>) -> Self {
+        self.on_paste_rows = on_paste_rows;
+        self
+    }
+
+    pub fn on_row_toggle(mut self, on_row_toggle: Option<fn(usize, bool) -> Message>) -> Self {
+        self.on_row_toggle = on_row_toggle;
+        self
+    }
+
+    pub fn on_zoom(mut self, on_zoom: Option<fn(f32) -> Message>, scale: f32) -> Self {
+        self.on_zoom = on_zoom;
+        self.scale = scale;
+        self
+    }
+
+    // The layout holding the cells of a row, past the widgets wrapping them
+    fn cells<'b>(&self, row: Layout<'b>) -> Layout<'b> {
+        (0..self.nested_cells).fold(row, |row, _| row.children().next().unwrap_or(row))
+    }
+
+    fn row_at(layout: Layout<'_>, cursor: Cursor) -> Option<usize> {
+        layout
+            .children()
+            .next()
+            .unwrap()
+            .children()
+            .position(|row| cursor.is_over(row.bounds()))
+    }
+
+    // The row & visible position of the cell under the cursor
+    fn cell_at(&self, layout: Layout<'_>, cursor: Cursor) -> Option<(usize, usize)> {
+        let row = Self::row_at(layout, cursor)?;
+        let cells = self.cells(layout.children().next().unwrap().children().nth(row)?);
+
+        let cell = cells
+            .children()
+            .take(self.columns)
+            .position(|cell| cursor.is_over(cell.bounds()))?;
+
+        Some((row, cell))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Body<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer,
+    Theme: style::Catalog,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content]);
+
+        let state = tree.state.downcast_ref::<State>();
+        self.focused_row.set(state.focus.map(|(row, _)| row));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if let Some(budget) = &self.budget {
+            budget.start(tree.state.downcast_ref::<State>().visible_rows.clone());
+        }
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        state.is_budget_pending = self.budget.as_ref().is_some_and(Budget::is_pending);
+        state.rows = content
+            .children()
+            .iter()
+            .map(|row| (row.bounds().y, row.bounds().height))
+            .collect();
+        state.columns = self.columns;
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let event::Event::Window(window::Event::RedrawRequested(now)) = event {
+            // Build the next cells once the current ones made it to the screen
+            if state.is_budget_pending {
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+
+            if let Some(flash) = &mut state.flash {
+                let start = *flash.start.get_or_insert(*now);
+
+                flash.progress = now.saturating_duration_since(start).as_secs_f32()
+                    / FLASH_DURATION.as_secs_f32();
+
+                if flash.progress >= 1.0 {
+                    state.flash = None;
+                    shell.request_redraw();
+                } else if self.reduce_motion {
+                    // Nothing changes until the flash ends
+                    shell.request_redraw_at(start + FLASH_DURATION);
+                } else {
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        if let event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = *modifiers;
+        }
+
+        if let event::Event::Mouse(mouse::Event::ButtonPressed(_))
+        | event::Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = cursor.is_over(*viewport);
+
+            if let Some(cell) = self.cell_at(layout, cursor).filter(|_| state.is_focused) {
+                state.focus = Some(cell);
+            }
+        }
+
+        // The focus may also have been moved by the navigation keys since the last event
+        self.focused_row.set(state.focus.map(|(row, _)| row));
+
+        // Zooming takes precedence over scrolling the body or any scrollable within a cell
+        if let Some(on_zoom) = self.on_zoom {
+            if let event::Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+                if state.modifiers.command() && cursor.is_over(*viewport) {
+                    let steps = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => *y,
+                        mouse::ScrollDelta::Pixels { y, .. } => *y / 60.0,
+                    };
+
+                    shell.publish((on_zoom)(
+                        (self.scale * ZOOM_STEP.powf(steps)).clamp(MIN_SCALE, MAX_SCALE),
+                    ));
+                    shell.capture_event();
+                    return;
+                }
+            }
+        }
+
+        if self.on_visible_rows.is_some() || self.budget.is_some() {
+            // The viewport is given in the coordinates of the scrolled content
+            let mut visible = layout
+                .children()
+                .next()
+                .unwrap()
+                .children()
+                .enumerate()
+                .filter(|(_, row)| row.bounds().intersects(viewport))
+                .map(|(index, _)| index);
+
+            let visible_rows = visible
+                .next()
+                .map_or(0..0, |first| first..visible.last().unwrap_or(first) + 1);
+
+            if state.visible_rows.as_ref() != Some(&visible_rows) {
+                if let Some(on_visible_rows) = self.on_visible_rows {
+                    shell.publish((on_visible_rows)(visible_rows.clone()));
+                }
+
+                // Rows scrolled into view get their cells built
+                if self.budget.is_some() {
+                    shell.invalidate_layout();
+                }
+
+                state.visible_rows = Some(visible_rows);
+            }
+        }
+
+        if let Some(on_file_drop) = self.on_file_drop {
+            match event {
+                // Each file of a multi-file drop arrives as its own event, so they are
+                // collected and published together on the next frame
+                event::Event::Window(window::Event::FileDropped(path))
+                    if cursor.is_over(*viewport) =>
+                {
+                    if state.dropped_files.is_empty() {
+                        state.drop_row = Self::row_at(layout, cursor);
+                    }
+
+                    state.dropped_files.push(path.clone());
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                event::Event::Window(window::Event::RedrawRequested(_))
+                    if !state.dropped_files.is_empty() =>
+                {
+                    shell.publish((on_file_drop)(
+                        std::mem::take(&mut state.dropped_files),
+                        state.drop_row.take(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // Pasting into a cell editor takes precedence, so only uncaptured pastes create rows.
+        // Editors outside of the table may see the paste after the body, so the rows must
+        // have been clicked last
+        if let Some(on_paste_rows) = self.on_paste_rows.filter(|_| state.is_focused) {
+            if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) = event
+            {
+                if c.as_str() == "v" && modifiers.command() && !shell.is_event_captured() {
+                    let rows = clipboard
+                        .read(clipboard::Kind::Standard)
+                        .map(|text| {
+                            import::parse_with(&text, import::sniff_delimiter(&text), false).rows
+                        })
+                        .unwrap_or_default();
+
+                    // A single line without a delimiter isn't tabular, so it is left alone,
+                    // while several lines make rows of a single column
+                    if rows.len() > 1 || rows.iter().any(|row| row.len() > 1) {
+                        shell.publish((on_paste_rows)(rows));
+                        shell.capture_event();
+                    }
+                }
+            }
+        }
+
+        // Likewise, a focused checkbox or text input within a cell handles Space itself
+        if let Some(on_row_toggle) = self.on_row_toggle.filter(|_| state.is_focused) {
+            if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Space),
+                modifiers,
+                ..
+            }) = event
+            {
+                if let Some((row, _)) = state.focus.filter(|_| !shell.is_event_captured()) {
+                    shell.publish((on_row_toggle)(row, modifiers.shift()));
+                    shell.capture_event();
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        let fill = |renderer: &mut Renderer, bounds, appearance: container::Style| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    shadow: appearance.shadow,
+                },
+                appearance
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+        };
+
+        if let Some(row) = state
+            .highlight
+            .and_then(|index| layout.children().next().unwrap().children().nth(index))
+        {
+            fill(renderer, row.bounds(), theme.row_highlight(&self.style));
+
+            let row = self.cells(row);
+
+            for cell in row
+                .children()
+                .enumerate()
+                .filter(|(position, _)| state.highlight_cells.contains(position))
+                .map(|(_, cell)| cell)
+            {
+                fill(renderer, cell.bounds(), theme.cell_highlight(&self.style));
+            }
+        }
+
+        if let Some(cell) = state.focus.and_then(|(row, cell)| {
+            let row = layout.children().next().unwrap().children().nth(row)?;
+
+            self.cells(row).children().nth(cell)
+        }) {
+            fill(renderer, cell.bounds(), theme.focused_cell(&self.style));
+        }
+
+        if let Some((flash, row)) = state.flash.as_ref().and_then(|flash| {
+            let row = layout
+                .children()
+                .next()
+                .unwrap()
+                .children()
+                .nth(flash.row)?;
+
+            Some((flash, row))
+        }) {
+            // Fade out over the duration of the flash, unless motion is reduced
+            let alpha = if self.reduce_motion {
+                1.0
+            } else {
+                1.0 - flash.progress.clamp(0.0, 1.0)
+            };
+            let fade = |color: Color| Color {
+                a: color.a * alpha,
+                ..color
+            };
+
+            let mut appearance = theme.row_highlight(&self.style);
+
+            appearance.background = appearance.background.map(|background| match background {
+                Background::Color(color) => Background::Color(fade(color)),
+                background => background,
+            });
+            appearance.border.color = fade(appearance.border.color);
+
+            fill(renderer, row.bounds(), appearance);
+        }
+
+        if self.debug {
+            let content = layout.children().next().unwrap();
+
+            for row in content
+                .children()
+                .filter(|row| row.bounds().intersects(viewport))
+            {
+                for cell in self.cells(row).children() {
+                    debug::outline(renderer, cell.bounds(), debug::COLUMN);
+                }
+            }
+
+            // The viewport is given in the coordinates of the scrolled content
+            let offset = viewport.position() - content.bounds().position();
+
+            debug::label(
+                renderer,
+                format!("x: {:.0}, y: {:.0}", offset.x, offset.y),
+                viewport.position() + Vector::new(4.0, 4.0),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.custom(None, layout.bounds(), tree.state.downcast_mut::<State>());
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Body<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(body: Body<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(body)
+    }
+}
+
+// Flashes a row of the body within the target scrollable, then scrolls to it
+pub(crate) struct FlashRow {
+    target: widget::Id,
+    row: usize,
+    is_target: bool,
+    geometry: Option<(f32, f32)>,
+    skip_children: bool,
+}
+
+impl FlashRow {
+    pub fn new(target: widget::Id, row: usize) -> Self {
+        Self {
+            target,
+            row,
+            is_target: false,
+            geometry: None,
+            skip_children: false,
+        }
+    }
+}
+
+impl Operation for FlashRow {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if self.geometry.is_none() && !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+        _state: &mut dyn operation::Scrollable,
+    ) {
+        // The body is the direct content of the target scrollable
+        self.is_target = id == Some(&self.target);
+        self.skip_children = !self.is_target;
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        state: &mut dyn std::any::Any,
+    ) {
+        if !std::mem::take(&mut self.is_target) {
+            return;
+        }
+
+        if let Some(state) = state.downcast_mut::<State>() {
+            if let Some(geometry) = state.rows.get(self.row).copied() {
+                state.flash = Some(Flash {
+                    row: self.row,
+                    start: None,
+                    progress: 0.0,
+                });
+
+                self.geometry = Some(geometry);
+            }
+        }
+    }
+
+    fn finish(&self) -> operation::Outcome<()> {
+        match self.geometry {
+            Some((y, height)) => operation::Outcome::Chain(Box::new(ScrollTo::new(
+                self.target.clone(),
+                y + height / 2.0,
+            ))),
+            None => operation::Outcome::None,
+        }
+    }
+}
+
+/// Where keyboard navigation moves the focused cell of the body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Navigation {
+    /// The first row, keeping the cell.
+    FirstRow,
+    /// The last row, keeping the cell.
+    LastRow,
+    /// The top-left cell.
+    FirstCell,
+    /// The bottom-right cell.
+    LastCell,
+    /// A page of rows up, keeping the cell.
+    PageUp,
+    /// A page of rows down, keeping the cell.
+    PageDown,
+}
+
+// Moves the focused cell of the body within the target scrollable, then scrolls to it
+// unless it is still visible
+pub(crate) struct MoveFocus {
+    target: widget::Id,
+    navigation: Navigation,
+    // The top & bottom of the visible part of the body
+    visible: Option<(f32, f32)>,
+    geometry: Option<(f32, f32)>,
+    is_done: bool,
+    skip_children: bool,
+}
+
+impl MoveFocus {
+    pub fn new(target: widget::Id, navigation: Navigation) -> Self {
+        Self {
+            target,
+            navigation,
+            visible: None,
+            geometry: None,
+            is_done: false,
+            skip_children: false,
+        }
+    }
+}
+
+impl Operation for MoveFocus {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if !self.is_done && !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn operation::Scrollable,
+    ) {
+        // The body is the direct content of the target scrollable
+        self.visible =
+            (id == Some(&self.target)).then_some((translation.y, translation.y + bounds.height));
+        self.skip_children = self.visible.is_none();
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        state: &mut dyn std::any::Any,
+    ) {
+        let Some((top, bottom)) = self.visible.take() else {
+            return;
+        };
+
+        self.is_done = true;
+
+        let Some(state) = state.downcast_mut::<State>() else {
+            return;
+        };
+
+        let Some(last_row) = state.rows.len().checked_sub(1) else {
+            return;
+        };
+
+        let row_at = |y: f32| {
+            state
+                .rows
+                .iter()
+                .position(|(row_y, height)| y < row_y + height)
+                .unwrap_or(last_row)
+        };
+
+        // Without a focused cell, navigation starts from the first visible row
+        let (row, cell) = state
+            .focus
+            .map_or((row_at(top), 0), |(row, cell)| (row.min(last_row), cell));
+
+        // The body was already scrolled by a page, so the focus keeps its place on screen
+        let page = |direction: f32| {
+            let (y, height) = state.rows[row];
+
+            row_at(y + height / 2.0 + direction * (bottom - top))
+        };
+
+        let focus = match self.navigation {
+            Navigation::FirstRow => (0, cell),
+            Navigation::LastRow => (last_row, cell),
+            Navigation::FirstCell => (0, 0),
+            Navigation::LastCell => (last_row, state.columns.saturating_sub(1)),
+            Navigation::PageUp => (page(-1.0), cell),
+            Navigation::PageDown => (page(1.0), cell),
+        };
+
+        state.focus = Some(focus);
+
+        let (y, height) = state.rows[focus.0];
+
+        if y < top || y + height > bottom {
+            self.geometry = Some((y, height));
+        }
+    }
+
+    fn finish(&self) -> operation::Outcome<()> {
+        match self.geometry {
+            Some((y, height)) => operation::Outcome::Chain(Box::new(ScrollTo::new(
+                self.target.clone(),
+                y + height / 2.0,
+            ))),
+            None => operation::Outcome::None,
+        }
+    }
+}
@@ -0,0 +1,560 @@
+use std::cell::{Cell, OnceCell};
+use std::ops::Range;
+use std::rc::Rc;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::text;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::{event, mouse, overlay, Color, Element, Length, Rectangle, Size, Vector};
+
+use crate::style;
+
+/// The number of cells built per frame, shared between the body & its cells.
+#[derive(Debug, Clone)]
+pub(crate) struct Budget {
+    per_frame: usize,
+    frame: Rc<Frame>,
+}
+
+// Reset by the body before each layout of its content
+#[derive(Debug, Default)]
+struct Frame {
+    // The rows visible after the last update, the only ones built
+    visible: Cell<(usize, usize)>,
+    remaining: Cell<usize>,
+    // Whether a visible cell was left as a placeholder
+    is_pending: Cell<bool>,
+}
+
+impl Budget {
+    pub fn new(per_frame: usize) -> Self {
+        Self {
+            per_frame,
+            frame: Rc::default(),
+        }
+    }
+
+    /// Starts a layout of the body, given the rows it displayed last.
+    ///
+    /// Before the first update, the rows are built from the top.
+    pub fn start(&self, visible: Option<Range<usize>>) {
+        let visible = visible.map_or((0, usize::MAX), |rows| (rows.start, rows.end));
+
+        self.frame.visible.set(visible);
+        self.frame.remaining.set(self.per_frame);
+        self.frame.is_pending.set(false);
+    }
+
+    /// Returns whether visible cells are still waiting for the next frame.
+    pub fn is_pending(&self) -> bool {
+        self.frame.is_pending.get()
+    }
+
+    // Whether the row is visible or close enough to be scrolled into view soon, keeping
+    // a screen of rows on either side. Before the first update, only the rows at the top
+    // that can get a cell built this frame are
+    fn is_near(&self, row: usize) -> bool {
+        match self.frame.visible.get() {
+            (_, usize::MAX) => row < self.per_frame,
+            (start, end) => {
+                let margin = (end - start).max(1);
+
+                (start.saturating_sub(margin)..end + margin).contains(&row)
+            }
+        }
+    }
+
+    // Whether a cell of the row can be built within this frame
+    fn claim(&self, row: usize) -> bool {
+        let (start, end) = self.frame.visible.get();
+
+        if !(start..end).contains(&row) {
+            return false;
+        }
+
+        match self.frame.remaining.get() {
+            0 => {
+                self.frame.is_pending.set(true);
+                false
+            }
+            remaining => {
+                self.frame.remaining.set(remaining - 1);
+                true
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // The last layout of the content, kept until the next view
+    cache: Option<(layout::Limits, layout::Node)>,
+}
+
+/// Wraps a cell that is only built once the [`Budget`] reaches it, standing in a
+/// placeholder for it until then.
+///
+/// Once built, the layout of the cell is reused until the table is rebuilt, so revealing
+/// more cells doesn't lay out the previous ones again.
+pub(crate) struct Deferred<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    build: Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>,
+    content: OnceCell<Element<'a, Message, Theme, Renderer>>,
+    row: usize,
+    width: f32,
+    budget: Budget,
+    style: Rc<<Theme as style::Catalog>::Style>,
+}
+
+impl<'a, Message, Theme, Renderer> Deferred<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    pub fn new(
+        build: impl Fn() -> Element<'a, Message, Theme, Renderer> + 'a,
+        row: usize,
+        width: f32,
+        budget: Budget,
+        style: Rc<<Theme as style::Catalog>::Style>,
+    ) -> Self {
+        Self {
+            build: Box::new(build),
+            content: OnceCell::new(),
+            row,
+            width,
+            budget,
+            style,
+        }
+    }
+
+    fn content(&self) -> &Element<'a, Message, Theme, Renderer> {
+        self.content.get_or_init(|| (self.build)())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Deferred<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.content
+            .get()
+            .map(|content| vec![widget::Tree::new(content)])
+            .unwrap_or_default()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.state.downcast_mut::<State>().cache = None;
+
+        // A cell built in a previous frame keeps its state, so it is built right away
+        if !tree.children.is_empty() {
+            tree.diff_children(&[self.content()]);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        match self.content.get() {
+            Some(content) => content.as_widget().size(),
+            None => Size::new(Length::Fixed(self.width), Length::Shrink),
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if tree.children.is_empty() {
+            if !self.budget.claim(self.row) {
+                // A single line of text keeps the row close to its final height
+                let height = text::LineHeight::default()
+                    .to_absolute(renderer.default_size())
+                    .0;
+
+                return layout::Node::new(limits.resolve(
+                    Length::Fixed(self.width),
+                    Length::Shrink,
+                    Size::new(self.width, height),
+                ));
+            }
+
+            tree.children = vec![widget::Tree::new(self.content())];
+        }
+
+        if let Some((cached, node)) = &tree.state.downcast_ref::<State>().cache {
+            if cached == limits {
+                return node.clone();
+            }
+        }
+
+        let content = self
+            .content()
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let node = layout::Node::with_children(content.size(), vec![content]);
+
+        tree.state.downcast_mut::<State>().cache = Some((*limits, node.clone()));
+
+        node
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let (Some(content), Some(layout)) = (self.content.get_mut(), layout.children().next())
+        else {
+            return;
+        };
+
+        content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (Some(content), Some(layout)) = (self.content.get(), layout.children().next()) else {
+            return mouse::Interaction::default();
+        };
+
+        content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let (Some(content), Some(layout)) = (self.content.get(), layout.children().next()) {
+            content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let appearance = theme.placeholder(&self.style);
+
+        // A bar in the middle of the cell, like a line of text yet to come
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + bounds.height / 4.0,
+                    height: bounds.height / 2.0,
+                    ..bounds.shrink(2.0)
+                },
+                border: appearance.border,
+                shadow: Default::default(),
+            },
+            appearance
+                .background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.get_mut()?.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if let (Some(content), Some(layout)) = (self.content.get(), layout.children().next()) {
+            content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, operation);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Deferred<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(deferred: Deferred<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(deferred)
+    }
+}
+
+/// Wraps a row, only building it & its [`Deferred`] cells while the [`Budget`] finds it
+/// near the visible rows, and standing in an empty space of a fixed size for it otherwise.
+///
+/// A row scrolled away is dropped along with its state on the next layout.
+pub(crate) struct DeferredRow<'a, Message, Theme, Renderer> {
+    build: Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>,
+    content: OnceCell<Element<'a, Message, Theme, Renderer>>,
+    row: usize,
+    width: f32,
+    // The fixed height of the row, if any, or a line of text
+    height: Option<f32>,
+    budget: Budget,
+}
+
+impl<'a, Message, Theme, Renderer> DeferredRow<'a, Message, Theme, Renderer> {
+    pub fn new(
+        build: impl Fn() -> Element<'a, Message, Theme, Renderer> + 'a,
+        row: usize,
+        width: f32,
+        height: Option<f32>,
+        budget: Budget,
+    ) -> Self {
+        Self {
+            build: Box::new(build),
+            content: OnceCell::new(),
+            row,
+            width,
+            height,
+            budget,
+        }
+    }
+
+    fn content(&self) -> &Element<'a, Message, Theme, Renderer> {
+        self.content.get_or_init(|| (self.build)())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DeferredRow<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<widget::Tree> {
+        self.content
+            .get()
+            .map(|content| vec![widget::Tree::new(content)])
+            .unwrap_or_default()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        // A row built by the last layout keeps its state, so it is built right away
+        if !tree.children.is_empty() {
+            tree.diff_children(&[self.content()]);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        match self.content.get() {
+            Some(content) => content.as_widget().size(),
+            None => Size::new(Length::Fixed(self.width), Length::Shrink),
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if !self.budget.is_near(self.row) {
+            tree.children.clear();
+
+            let height = self.height.unwrap_or_else(|| {
+                text::LineHeight::default()
+                    .to_absolute(renderer.default_size())
+                    .0
+            });
+
+            return layout::Node::new(limits.resolve(
+                Length::Fixed(self.width),
+                Length::Shrink,
+                Size::new(self.width, height),
+            ));
+        }
+
+        if tree.children.is_empty() {
+            tree.children = vec![widget::Tree::new(self.content())];
+        }
+
+        let content = self
+            .content()
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let (Some(content), Some(layout)) = (self.content.get_mut(), layout.children().next())
+        else {
+            return;
+        };
+
+        content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (Some(content), Some(layout)) = (self.content.get(), layout.children().next()) else {
+            return mouse::Interaction::default();
+        };
+
+        content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let (Some(content), Some(layout)) = (self.content.get(), layout.children().next()) {
+            content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.get_mut()?.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if let (Some(content), Some(layout)) = (self.content.get(), layout.children().next()) {
+            content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, operation);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DeferredRow<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(row: DeferredRow<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(row)
+    }
+}
@@ -0,0 +1,542 @@
+//! Manage the state of the columns of a [`Table`](crate::Table).
+use std::ops::Deref;
+
+use iced::Element;
+
+use crate::divider::ColumnVisibilityMessage;
+use crate::table;
+
+/// The default minimum width of a column, shared with the [`Table`](crate::Table).
+pub(crate) const MIN_COLUMN_WIDTH: f32 = 4.0;
+
+/// A list of columns along with their widths, on-going resize offsets & visibility.
+///
+/// [`Columns`] derefs to a slice of [`Entry`], which implements [`Column`](table::Column)
+/// by forwarding to the wrapped column, so it can be passed directly to [`table`](crate::table).
+#[derive(Debug, Clone)]
+pub struct Columns<T> {
+    entries: Vec<Entry<T>>,
+    min_width: f32,
+    auto_hidden: Vec<String>,
+    header_height: Option<f32>,
+}
+
+/// The width & visibility of a column, identified by its [`id`](table::Column::id).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnLayout {
+    /// The [`id`](table::Column::id) of the column.
+    pub id: String,
+    /// The stored width of the column.
+    pub width: f32,
+    /// Whether the column is visible.
+    pub is_visible: bool,
+}
+
+/// A column managed by [`Columns`].
+#[derive(Debug, Clone)]
+pub struct Entry<T> {
+    column: T,
+    width: f32,
+    resize_offset: Option<f32>,
+    is_visible: bool,
+    // Set by the owning Columns, so widths set through the entry are clamped alike
+    min_width: f32,
+}
+
+impl<T> Columns<T> {
+    /// Creates a new [`Columns`] from the provided column definitions.
+    ///
+    /// The initial width & visibility of each column are taken from
+    /// [`Column::width`](table::Column::width) and
+    /// [`Column::is_visible`](table::Column::is_visible).
+    pub fn new<'a, Message, Theme, Renderer>(columns: impl IntoIterator<Item = T>) -> Self
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        Self {
+            entries: columns
+                .into_iter()
+                .map(|column| Entry {
+                    width: column.width(),
+                    is_visible: column.is_visible(),
+                    resize_offset: None,
+                    min_width: MIN_COLUMN_WIDTH,
+                    column,
+                })
+                .collect(),
+            min_width: MIN_COLUMN_WIDTH,
+            auto_hidden: Vec::new(),
+            header_height: None,
+        }
+    }
+
+    /// Sets the minimum width a column can be set to.
+    ///
+    /// This should match [`Table::min_column_width`](crate::Table::min_column_width) so
+    /// programmatic widths follow the same rules as interactive resizing. Both default to 4.
+    pub fn min_column_width(mut self, min_width: f32) -> Self {
+        for entry in &mut self.entries {
+            entry.min_width = min_width;
+        }
+
+        Self { min_width, ..self }
+    }
+
+    /// Sets the width of the column at `index`, clamped to the minimum column width.
+    ///
+    /// Any on-going resize of the column is discarded. This is useful to drive widths from
+    /// a settings dialog or layout preset.
+    pub fn set_width(&mut self, index: usize, width: f32) {
+        if let Some(entry) = self.entries.get_mut(index) {
+            entry.set_width(width);
+            entry.resize_offset = None;
+        }
+    }
+
+    /// Stores the offset of an on-going resize of the column at `index`.
+    ///
+    /// Use this to handle the `on_drag` message of
+    /// [`Table::on_column_resize`](crate::Table::on_column_resize).
+    pub fn resize(&mut self, index: usize, offset: f32) {
+        if let Some(entry) = self.entries.get_mut(index) {
+            entry.resize_offset = Some(offset);
+        }
+    }
+
+    /// Applies every on-going resize offset to the stored width of its column.
+    ///
+    /// Use this to handle the `on_release` message of
+    /// [`Table::on_column_resize`](crate::Table::on_column_resize).
+    pub fn apply_resize(&mut self) {
+        for entry in &mut self.entries {
+            if let Some(offset) = entry.resize_offset.take() {
+                entry.set_width(entry.width + offset);
+            }
+        }
+    }
+
+    /// Returns the height the header was resized to, if any.
+    ///
+    /// Pass it to [`Table::header_height`](crate::Table::header_height) to keep the header at
+    /// this height.
+    pub fn header_height(&self) -> Option<f32> {
+        self.header_height
+    }
+
+    /// Sets the height of the header.
+    ///
+    /// Use this to handle the message of
+    /// [`Table::on_header_resize`](crate::Table::on_header_resize).
+    pub fn set_header_height(&mut self, height: f32) {
+        self.header_height = Some(height);
+    }
+
+    /// Moves the column at `from` to `to`.
+    ///
+    /// Use this to handle the message of
+    /// [`Table::on_column_reorder`](crate::Table::on_column_reorder).
+    pub fn reorder(&mut self, from: usize, to: usize) {
+        if from < self.entries.len() && to < self.entries.len() {
+            let entry = self.entries.remove(from);
+            self.entries.insert(to, entry);
+        }
+    }
+
+    /// Toggles the visibility of the column with the given [`id`](table::Column::id).
+    pub fn toggle<'a, Message, Theme, Renderer>(&mut self, id: &str)
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .enumerate()
+            .find(|(index, entry)| entry.column.id(*index) == id)
+            .map(|(_, entry)| entry)
+        {
+            entry.is_visible = !entry.is_visible;
+        }
+    }
+
+    /// Applies a [`ColumnVisibilityMessage`] emitted by the [`Table`](crate::Table).
+    pub fn update_visibility<'a, Message, Theme, Renderer>(
+        &mut self,
+        message: ColumnVisibilityMessage,
+    ) where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        match message {
+            ColumnVisibilityMessage::ToggleColumn(id) => self.toggle(&id),
+            ColumnVisibilityMessage::HideContextMenu => {}
+            ColumnVisibilityMessage::AutoHidden(ids) => self.auto_hidden = ids,
+        }
+    }
+
+    /// Returns the order, widths & visibility of the columns.
+    pub fn layout<'a, Message, Theme, Renderer>(&self) -> Vec<ColumnLayout>
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| ColumnLayout {
+                id: entry.column.id(index),
+                width: entry.width,
+                is_visible: entry.is_visible,
+            })
+            .collect()
+    }
+
+    /// Restores a layout returned by [`Columns::layout`].
+    ///
+    /// Columns are ordered as they appear in `layout`, followed by any column missing from
+    /// it in their current order. Entries of `layout` matching no column are ignored.
+    ///
+    /// Columns sharing an id are matched with the entries of `layout` in order.
+    pub fn apply_layout<'a, Message, Theme, Renderer>(&mut self, layout: &[ColumnLayout])
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        let mut is_matched = vec![false; layout.len()];
+
+        // Ids may depend on the position of a column, so they are resolved before sorting.
+        // Duplicate ids are matched in order, the n-th column with an id taking the n-th
+        // entry with it.
+        let mut entries: Vec<_> = std::mem::take(&mut self.entries)
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut entry)| {
+                let id = entry.column.id(index);
+                let position = (0..layout.len())
+                    .find(|position| !is_matched[*position] && layout[*position].id == id);
+
+                if let Some(position) = position {
+                    is_matched[position] = true;
+                    entry.set_width(layout[position].width);
+                    entry.is_visible = layout[position].is_visible;
+                    entry.resize_offset = None;
+                }
+
+                (position.unwrap_or(layout.len()), entry)
+            })
+            .collect();
+
+        // Stable, so columns missing from the layout keep their relative order
+        entries.sort_by_key(|(position, _)| *position);
+
+        self.entries = entries.into_iter().map(|(_, entry)| entry).collect();
+    }
+
+    /// Replaces the column definitions with `columns`, keeping the order, width & visibility
+    /// of the columns whose [`id`](table::Column::id) is already known.
+    ///
+    /// This is useful when a layout was persisted by a previous version of the application:
+    /// removed columns are dropped, while added columns take their initial width &
+    /// visibility and are placed after the known ones.
+    pub fn reconcile<'a, Message, Theme, Renderer>(&mut self, columns: impl IntoIterator<Item = T>)
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        let layout = self.layout();
+
+        self.entries = Self::new(columns).min_column_width(self.min_width).entries;
+        self.apply_layout(&layout);
+    }
+
+    /// Returns the ids of the columns currently hidden because the table is too narrow.
+    ///
+    /// See [`Table::responsive_columns`](crate::Table::responsive_columns).
+    pub fn auto_hidden(&self) -> &[String] {
+        &self.auto_hidden
+    }
+
+    /// Returns the managed columns.
+    pub fn as_slice(&self) -> &[Entry<T>] {
+        &self.entries
+    }
+
+    /// Returns a mutable iterator over the managed columns.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Entry<T>> {
+        self.entries.iter_mut()
+    }
+}
+
+impl<T> Deref for Columns<T> {
+    type Target = [Entry<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl<T> Entry<T> {
+    /// Returns the wrapped column definition.
+    pub fn column(&self) -> &T {
+        &self.column
+    }
+
+    /// Returns the wrapped column definition mutably.
+    pub fn column_mut(&mut self) -> &mut T {
+        &mut self.column
+    }
+
+    /// Sets the stored width of this column, clamped to the
+    /// [minimum column width](Columns::min_column_width) like [`Columns::set_width`].
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width.max(self.min_width);
+    }
+
+    /// Sets whether this column is visible.
+    pub fn set_visible(&mut self, is_visible: bool) {
+        self.is_visible = is_visible;
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer> for Entry<T>
+where
+    T: table::Column<'a, Message, Theme, Renderer>,
+{
+    type Row = T::Row;
+
+    fn header(&'a self, col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        self.column.header(col_index)
+    }
+
+    fn cell(
+        &'a self,
+        col_index: usize,
+        row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        self.column.cell(col_index, row_index, row)
+    }
+
+    fn footer(
+        &'a self,
+        col_index: usize,
+        rows: &'a [Self::Row],
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        self.column.footer(col_index, rows)
+    }
+
+    fn header_summary(
+        &'a self,
+        col_index: usize,
+        rows: &'a [Self::Row],
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        self.column.header_summary(col_index, rows)
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn resize_offset(&self) -> Option<f32> {
+        self.resize_offset
+    }
+
+    fn id(&self, col_index: usize) -> String {
+        self.column.id(col_index)
+    }
+
+    fn title(&self) -> String {
+        self.column.title()
+    }
+
+    fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    fn hideable(&self) -> bool {
+        self.column.hideable()
+    }
+
+    fn search_text(&self, row: &Self::Row) -> Option<String> {
+        self.column.search_text(row)
+    }
+
+    fn cell_tooltip(&self, row: &Self::Row) -> Option<String> {
+        self.column.cell_tooltip(row)
+    }
+
+    fn merge_key(&self, row: &Self::Row) -> Option<String> {
+        self.column.merge_key(row)
+    }
+
+    fn priority(&self) -> u8 {
+        self.column.priority()
+    }
+
+    fn divider_style(&self) -> Option<crate::DividerVariant> {
+        self.column.divider_style()
+    }
+
+    fn resizable(&self) -> bool {
+        self.column.resizable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::widget::text;
+    use iced::{Renderer, Theme};
+
+    #[derive(Debug, Clone)]
+    struct TestColumn {
+        id: &'static str,
+        width: f32,
+    }
+
+    impl<'a> table::Column<'a, (), Theme, Renderer> for TestColumn {
+        type Row = ();
+
+        fn header(&'a self, _col_index: usize) -> Element<'a, (), Theme, Renderer> {
+            text(self.id).into()
+        }
+
+        fn cell(
+            &'a self,
+            _col_index: usize,
+            _row_index: usize,
+            _row: &'a Self::Row,
+        ) -> Element<'a, (), Theme, Renderer> {
+            text(self.id).into()
+        }
+
+        fn width(&self) -> f32 {
+            self.width
+        }
+
+        fn resize_offset(&self) -> Option<f32> {
+            None
+        }
+
+        fn id(&self, _col_index: usize) -> String {
+            String::from(self.id)
+        }
+    }
+
+    fn columns(columns: &[(&'static str, f32)]) -> Vec<TestColumn> {
+        columns
+            .iter()
+            .map(|&(id, width)| TestColumn { id, width })
+            .collect()
+    }
+
+    fn new(definitions: &[(&'static str, f32)]) -> Columns<TestColumn> {
+        Columns::new::<(), Theme, Renderer>(columns(definitions))
+    }
+
+    fn reconcile(columns: &mut Columns<TestColumn>, definitions: &[(&'static str, f32)]) {
+        columns.reconcile::<(), Theme, Renderer>(self::columns(definitions));
+    }
+
+    fn summary(columns: &Columns<TestColumn>) -> Vec<(&'static str, f32, bool)> {
+        columns
+            .iter()
+            .map(|entry| (entry.column.id, entry.width, entry.is_visible))
+            .collect()
+    }
+
+    #[test]
+    fn reconcile_added_column() {
+        let mut columns = new(&[("a", 10.0), ("b", 20.0)]);
+        columns.reorder(1, 0);
+        columns.set_width(0, 25.0);
+
+        reconcile(&mut columns, &[("a", 10.0), ("b", 20.0), ("c", 30.0)]);
+
+        assert_eq!(
+            summary(&columns),
+            vec![("b", 25.0, true), ("a", 10.0, true), ("c", 30.0, true)]
+        );
+    }
+
+    #[test]
+    fn reconcile_removed_column() {
+        let mut columns = new(&[("a", 10.0), ("b", 20.0), ("c", 30.0)]);
+        columns.set_width(2, 35.0);
+        columns.toggle::<(), Theme, Renderer>("c");
+
+        reconcile(&mut columns, &[("a", 10.0), ("c", 30.0)]);
+
+        assert_eq!(
+            summary(&columns),
+            vec![("a", 10.0, true), ("c", 35.0, false)]
+        );
+    }
+
+    #[test]
+    fn reconcile_renamed_column() {
+        let mut columns = new(&[("a", 10.0), ("b", 20.0)]);
+        columns.set_width(0, 15.0);
+
+        reconcile(&mut columns, &[("renamed", 10.0), ("b", 20.0)]);
+
+        assert_eq!(
+            summary(&columns),
+            vec![("b", 20.0, true), ("renamed", 10.0, true)]
+        );
+    }
+
+    #[test]
+    fn reconcile_duplicate_ids() {
+        let mut columns = new(&[("a", 10.0), ("a", 20.0), ("b", 30.0)]);
+        columns.set_width(0, 15.0);
+        columns.set_width(1, 25.0);
+
+        reconcile(&mut columns, &[("a", 10.0), ("b", 30.0), ("a", 20.0)]);
+
+        assert_eq!(
+            summary(&columns),
+            vec![("a", 15.0, true), ("a", 25.0, true), ("b", 30.0, true)]
+        );
+    }
+
+    #[test]
+    fn apply_empty_layout() {
+        let mut columns = new(&[("a", 10.0), ("b", 20.0)]);
+        columns.reorder(0, 1);
+        columns.set_width(0, 25.0);
+
+        columns.apply_layout::<(), Theme, Renderer>(&[]);
+
+        assert_eq!(
+            summary(&columns),
+            vec![("b", 25.0, true), ("a", 10.0, true)]
+        );
+    }
+
+    #[test]
+    fn apply_layout_clamps_widths() {
+        let mut columns = new(&[("a", 10.0)]).min_column_width(8.0);
+
+        columns.apply_layout::<(), Theme, Renderer>(&[ColumnLayout {
+            id: String::from("a"),
+            width: 2.0,
+            is_visible: false,
+        }]);
+
+        assert_eq!(summary(&columns), vec![("a", 8.0, false)]);
+    }
+
+    #[test]
+    fn entry_widths_are_clamped() {
+        let mut columns = new(&[("a", 10.0), ("b", 20.0)]).min_column_width(8.0);
+
+        for entry in columns.iter_mut() {
+            entry.set_width(2.0);
+        }
+
+        reconcile(&mut columns, &[("a", 10.0), ("b", 20.0), ("c", 30.0)]);
+        columns.iter_mut().last().unwrap().set_width(1.0);
+
+        assert_eq!(
+            summary(&columns),
+            vec![("a", 8.0, true), ("b", 8.0, true), ("c", 8.0, true)]
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::time::{Duration, Instant};
+use iced::{event, mouse, window, Element, Length, Rectangle, Size};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Default)]
+struct State {
+    armed: bool,
+    deadline: Option<Instant>,
+}
+
+/// Creates a new [`ConfirmButton`] showing `content` until it's clicked, then `confirm`
+/// until it's clicked again to publish `on_confirm`.
+///
+/// The button keeps track of whether it's armed by itself, which makes it a good fit for
+/// destructive actions living inside the cells of a [`Table`](crate::Table).
+pub fn confirm_button<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    confirm: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_confirm: Message,
+) -> ConfirmButton<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    ConfirmButton {
+        content: content.into(),
+        confirm: confirm.into(),
+        on_confirm,
+        timeout: DEFAULT_TIMEOUT,
+    }
+}
+
+/// A two-step button asking for confirmation before publishing its message.
+///
+/// The first click arms the button and swaps its content for the confirmation. Clicking it
+/// again publishes the message, while clicking elsewhere or waiting out the
+/// [`timeout`](Self::timeout) disarms it.
+#[allow(missing_debug_implementations)]
+pub struct ConfirmButton<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    confirm: Element<'a, Message, Theme, Renderer>,
+    on_confirm: Message,
+    timeout: Duration,
+}
+
+impl<'a, Message, Theme, Renderer> ConfirmButton<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Sets how long the [`ConfirmButton`] stays armed, 3 seconds by default.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    fn shown<'b>(
+        &'b self,
+        tree: &'b widget::Tree,
+    ) -> (&'b Element<'a, Message, Theme, Renderer>, &'b widget::Tree) {
+        if tree.state.downcast_ref::<State>().armed {
+            (&self.confirm, &tree.children[1])
+        } else {
+            (&self.content, &tree.children[0])
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ConfirmButton<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.content),
+            widget::Tree::new(&self.confirm),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content, &self.confirm]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (content, index) = if tree.state.downcast_ref::<State>().armed {
+            (&self.confirm, 1)
+        } else {
+            (&self.content, 0)
+        };
+
+        let content = content
+            .as_widget()
+            .layout(&mut tree.children[index], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if cursor.is_over(layout.bounds()) {
+                    if state.armed {
+                        shell.publish(self.on_confirm.clone());
+                    } else {
+                        shell.request_redraw();
+                    }
+
+                    state.armed = !state.armed;
+                    state.deadline = None;
+                    shell.invalidate_layout();
+                    shell.capture_event();
+                } else if state.armed {
+                    state.armed = false;
+                    state.deadline = None;
+                    shell.invalidate_layout();
+                }
+            }
+            event::Event::Window(window::Event::RedrawRequested(now)) if state.armed => {
+                // The timeout starts counting on the first frame showing the confirmation
+                let deadline = *state.deadline.get_or_insert(*now + self.timeout);
+
+                if *now >= deadline {
+                    state.armed = false;
+                    state.deadline = None;
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                } else {
+                    shell.request_redraw_at(deadline);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let (content, tree) = self.shown(tree);
+
+        content.as_widget().draw(
+            tree,
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ConfirmButton<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(button: ConfirmButton<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(button)
+    }
+}
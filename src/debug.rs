@@ -0,0 +1,143 @@
+//! Drawing helpers for the debug overlay of a [`Table`](crate::Table).
+use iced::advanced::layout::Layout;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, text};
+use iced::mouse::Cursor;
+use iced::{Border, Color, Element, Point, Rectangle, Size, Vector};
+
+use crate::forward::forward;
+
+/// The color outlining each column.
+pub(crate) const COLUMN: Color = Color::from_rgba(1.0, 0.0, 1.0, 0.8);
+
+/// The color outlining the filler column added to honor the minimum width.
+pub(crate) const FILLER: Color = Color::from_rgba(0.0, 0.8, 0.8, 0.8);
+
+/// The color filling the area where a divider can be grabbed.
+pub(crate) const GRAB_ZONE: Color = Color::from_rgba(1.0, 0.0, 0.0, 0.3);
+
+/// Draws the border of `bounds` in the given color.
+pub(crate) fn outline<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border::default().width(1.0).color(color),
+            shadow: Default::default(),
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+/// Draws `content` on a dark background with its top-left corner at `position`.
+pub(crate) fn label<Renderer>(renderer: &mut Renderer, content: String, position: Point)
+where
+    Renderer: text::Renderer,
+{
+    use iced::advanced::text::Paragraph;
+
+    let text = text::Text {
+        content,
+        bounds: Size::INFINITY,
+        size: iced::Pixels(12.0),
+        line_height: text::LineHeight::Relative(1.2),
+        font: renderer.default_font(),
+        align_x: text::Alignment::Left,
+        align_y: iced::alignment::Vertical::Top,
+        wrapping: text::Wrapping::None,
+        shaping: text::Shaping::Basic,
+    };
+
+    let size = Renderer::Paragraph::with_text(text.as_ref()).min_bounds();
+    let padding = Vector::new(4.0, 2.0);
+
+    let bounds = Rectangle::new(
+        position,
+        Size::new(size.width + padding.x * 2.0, size.height + padding.y * 2.0),
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..Default::default()
+        },
+        Color::from_rgba(0.0, 0.0, 0.0, 0.7),
+    );
+
+    renderer.fill_text(text, bounds.position() + padding, Color::WHITE, bounds);
+}
+
+/// Wraps some content and outlines it in the given color.
+pub(crate) struct Outline<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    color: Color,
+}
+
+impl<'a, Message, Theme, Renderer> Outline<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, color: Color) -> Self {
+        Self {
+            content: content.into(),
+            color,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Outline<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        outline(renderer, layout.bounds(), self.color);
+    }
+
+    forward!(
+        children,
+        diff,
+        size,
+        layout,
+        update,
+        mouse_interaction,
+        overlay,
+        operate
+    );
+}
+
+impl<'a, Message, Theme, Renderer> From<Outline<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(outline: Outline<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(outline)
+    }
+}
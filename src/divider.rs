@@ -1,20 +1,60 @@
 use iced::advanced::layout::{self, Layout};
 use iced::mouse::Cursor;
+use iced::advanced::widget::operation::Operation;
 use iced::advanced::widget::{self, Widget};
 use iced::wgpu::naga::back;
 use iced::{
-    event, mouse, overlay, padding, Color, Element, Length, Point, Rectangle, Size, Vector,
+    event, keyboard, mouse, overlay, padding, window, Color, Element, Length, Point, Rectangle,
+    Size, Vector,
 };
 use iced::advanced::{renderer, Clipboard, Overlay, Shell};
 
+use std::rc::Rc;
+
+use crate::debug;
+use crate::localization::Localization;
 use crate::style;
+use crate::sync::{Axis, Drag};
+
+// The resize offset of Alt+Left/Right without a resize step
+const KEY_STEP: f32 = 10.0;
 
 #[derive(Clone, Copy, Debug, Default)]
 struct State {
     drag_origin: Option<Point>,
+    // The cursor position of the last drag message
+    drag_position: Point,
     is_divider_hovered: bool,
     show_context_menu: bool,
     context_menu_position: Point,
+    // Whether the submenu listing the other columns is open
+    is_submenu_open: bool,
+    menu_scroll: f32,
+    modifiers: keyboard::Modifiers,
+    is_focused: bool,
+}
+
+impl State {
+    fn open_context_menu(&mut self, position: Point) {
+        self.context_menu_position = position;
+        self.show_context_menu = true;
+        self.is_submenu_open = false;
+        self.menu_scroll = 0.0;
+    }
+}
+
+impl widget::operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 /// Messages for column visibility management
@@ -24,6 +64,8 @@ pub enum ColumnVisibilityMessage {
     ToggleColumn(String),
     /// Hide the context menu
     HideContextMenu,
+    /// The IDs of the columns hidden because the table is too narrow
+    AutoHidden(Vec<String>),
 }
 
 pub(crate) struct Divider<'a, Message, Theme, Renderer>
@@ -35,14 +77,27 @@ where
     width: f32,
     column_id: String,
     column_title: String,
-    on_drag: Box<dyn Fn(f32) -> Message + 'a>,
-    on_release: Message,
+    // Receives the drag offset and whether it should snap to the other columns
+    on_drag: Box<dyn Fn(f32, bool) -> Message + 'a>,
+    // Shared between the dividers of a table, so it is only cloned once released
+    on_release: Rc<Message>,
+    on_drag_start: Option<Message>,
     on_column_visibility: Option<Box<dyn Fn(ColumnVisibilityMessage) -> Message + 'a>>,
-    style: <Theme as style::Catalog>::Style,
+    style: Rc<<Theme as style::Catalog>::Style>,
     // List of other columns that can be toggled
     other_columns: Vec<(String, String, bool)>, // (id, title, visible)
     // New field to control divider visibility
     always_show_divider: bool,
+    is_resizable: bool,
+    is_hideable: bool,
+    show_width: bool,
+    debug: bool,
+    menu_font: Option<iced::Font>,
+    menu_text_size: iced::Pixels,
+    localization: Localization,
+    variant: Option<style::DividerVariant>,
+    key_step: f32,
+    drag: Drag,
 }
 
 impl<'a, Message, Theme, Renderer> Divider<'a, Message, Theme, Renderer>
@@ -55,9 +110,9 @@ where
         width: f32,
         column_id: String,
         column_title: String,
-        on_drag: impl Fn(f32) -> Message + 'a,
-        on_release: Message,
-        style: <Theme as style::Catalog>::Style,
+        on_drag: impl Fn(f32, bool) -> Message + 'a,
+        on_release: Rc<Message>,
+        style: Rc<<Theme as style::Catalog>::Style>,
     ) -> Self {
         Self {
             content: content.into(),
@@ -66,13 +121,32 @@ where
             column_title,
             on_drag: Box::new(on_drag),
             on_release,
+            on_drag_start: None,
             on_column_visibility: None,
             style,
             other_columns: Vec::new(),
             always_show_divider: true,
+            is_resizable: true,
+            is_hideable: true,
+            show_width: false,
+            debug: false,
+            menu_font: None,
+            menu_text_size: iced::Pixels(14.0),
+            localization: Localization::default(),
+            variant: None,
+            key_step: KEY_STEP,
+            drag: Drag::default(),
         }
     }
 
+    /// Sets the offset a focused column is resized by with Alt+Left/Right.
+    pub fn key_step(mut self, key_step: Option<f32>) -> Self {
+        if let Some(key_step) = key_step {
+            self.key_step = key_step;
+        }
+        self
+    }
+
     pub fn with_column_visibility(
         mut self,
         on_column_visibility: impl Fn(ColumnVisibilityMessage) -> Message + 'a,
@@ -89,6 +163,94 @@ where
         self
     }
 
+    pub fn on_drag_start(mut self, on_drag_start: Option<Message>) -> Self {
+        self.on_drag_start = on_drag_start;
+        self
+    }
+
+    pub fn resizable(mut self, is_resizable: bool) -> Self {
+        self.is_resizable = is_resizable;
+        self
+    }
+
+    pub fn hideable(mut self, is_hideable: bool) -> Self {
+        self.is_hideable = is_hideable;
+        self
+    }
+
+    pub fn show_width(mut self, show_width: bool) -> Self {
+        self.show_width = show_width;
+        self
+    }
+
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    pub fn menu_font(mut self, menu_font: Option<iced::Font>) -> Self {
+        self.menu_font = menu_font;
+        self
+    }
+
+    pub fn menu_text_size(mut self, menu_text_size: iced::Pixels) -> Self {
+        self.menu_text_size = menu_text_size;
+        self
+    }
+
+    pub fn localization(mut self, localization: Localization) -> Self {
+        self.localization = localization;
+        self
+    }
+
+    pub fn variant(mut self, variant: Option<style::DividerVariant>) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn drag(mut self, drag: Drag) -> Self {
+        self.drag = drag;
+        self
+    }
+
+    // Grows with the text size, so larger fonts don't overflow their item
+    fn menu_item_height(&self) -> f32 {
+        (self.menu_text_size.0 * 2.0).max(30.0)
+    }
+
+    fn menu_text(&self, renderer: &Renderer, content: String) -> iced::advanced::text::Text
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        iced::advanced::text::Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.menu_text_size,
+            line_height: iced::advanced::text::LineHeight::Relative(1.2),
+            font: self.menu_font.unwrap_or_else(|| renderer.default_font()),
+            align_x: iced::advanced::text::Alignment::Left,
+            align_y: iced::alignment::Vertical::Center,
+            wrapping: iced::advanced::text::Wrapping::None,
+            shaping: iced::advanced::text::Shaping::Advanced,
+        }
+    }
+
+    fn measure_menu_text(&self, renderer: &Renderer, content: String) -> f32
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        use iced::advanced::text::Paragraph;
+
+        let text = self.menu_text(renderer, content);
+
+        Renderer::Paragraph::with_text(text.as_ref()).min_width()
+    }
+
+    // The separator only divides the current column from the others
+    fn has_separator(&self) -> bool {
+        self.is_hideable && !self.other_columns.is_empty()
+    }
+
     fn divider_bounds(&self, bounds: Rectangle) -> Rectangle {
         Rectangle {
             x: bounds.x + bounds.width - self.width,
@@ -133,56 +295,103 @@ where
         true
     }
 
-    fn context_menu_bounds(&self, position: Point, viewport_height: f32) -> (Rectangle, bool) {
-        let item_height = 30.0;
+    // The size of the menu, listing the current column & the entry opening the submenu
+    fn context_menu_size(&self, renderer: &Renderer) -> Size
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let item_height = self.menu_item_height();
         let padding = 10.0;
         let separator_height = 6.0;
-        let max_height = viewport_height * 0.8; // Use 80% of viewport height
-        
-        let item_count = 1 + // current column
-            if self.other_columns.is_empty() { 0 } else { 1 } + // separator
-            self.other_columns.len(); // other columns
-            
-        let content_height = (item_count as f32 * item_height) + 
-            if self.other_columns.is_empty() { 0.0 } else { separator_height };
-            
-        let desired_height = content_height + (padding * 2.0);
-        let actual_height = desired_height.min(max_height);
+
+        let item_count = if self.is_hideable { 1 } else { 0 } + // current column
+            if self.other_columns.is_empty() { 0 } else { 1 }; // submenu entry
+
+        let content_height = (item_count as f32 * item_height)
+            + if self.has_separator() {
+                separator_height
+            } else {
+                0.0
+            };
+
+        let current_title_width = if self.is_hideable {
+            self.measure_menu_text(
+                renderer,
+                (self.localization.hide_column)(&self.column_title),
+            )
+        } else {
+            0.0
+        };
+        // Leaving room for the arrow of the submenu
+        let submenu_title_width = if self.other_columns.is_empty() {
+            0.0
+        } else {
+            24.0 + self.measure_menu_text(renderer, self.localization.columns.clone())
+        };
+
+        let min_width = 180.0;
+        let content_width = current_title_width.max(submenu_title_width).max(min_width);
+
+        Size::new(
+            content_width + padding * 2.0,
+            content_height + padding * 2.0,
+        )
+    }
+
+    // The offset of the entry opening the submenu from the top of the menu
+    fn submenu_entry_y(&self) -> f32 {
+        let mut y = 8.0;
+
+        if self.is_hideable {
+            y += self.menu_item_height();
+        }
+
+        if self.has_separator() {
+            y += 6.0;
+        }
+
+        y
+    }
+
+    fn submenu_entry_bounds(&self, menu_bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            y: menu_bounds.y + self.submenu_entry_y(),
+            height: self.menu_item_height(),
+            ..menu_bounds
+        }
+    }
+
+    // The height of the other columns listed by the submenu, before it is scrolled
+    fn submenu_content_height(&self) -> f32 {
+        self.other_columns.len() as f32 * self.menu_item_height()
+    }
+
+    // The size of the submenu listing the other columns, scrolling past 80% of the viewport
+    fn submenu_size(&self, renderer: &Renderer, viewport_height: f32) -> Size
+    where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let padding = 10.0;
+        let max_height = viewport_height * 0.8;
+
+        let desired_height = self.submenu_content_height() + padding * 2.0;
         let needs_scroll = desired_height > max_height;
-        
-        // Calculate width
-        let current_title_width = self.column_title.len() as f32 * 8.0;
-        let max_other_width = self.other_columns
+
+        // Leaving room for the checkbox of each column
+        let max_title_width = self
+            .other_columns
             .iter()
-            .map(|(_, title, _)| title.len() as f32 * 8.0)
+            .map(|(_, title, _)| 24.0 + self.measure_menu_text(renderer, title.clone()))
             .fold(0.0, f32::max);
-            
+
         let min_width = 180.0;
-        let content_width = current_title_width.max(max_other_width).max(min_width);
-        let menu_width = content_width + (padding * 2.0) + if needs_scroll { 20.0 } else { 0.0 }; // Add space for scrollbar
-        
-        let menu_bounds = Rectangle {
-            x: position.x,
-            y: position.y,
-            width: menu_width,
-            height: actual_height,
-        };
-        
-        (menu_bounds, needs_scroll)
-    }
+        let content_width = max_title_width.max(min_width);
+        let width = content_width + padding * 2.0 + if needs_scroll { 20.0 } else { 0.0 }; // Add space for scrollbar
 
-    fn draw_context_menu(
-        &self,
-        renderer: &mut Renderer,
-        theme: &Theme,
-        bounds: Rectangle,
-        cursor: Cursor,
-        scroll_offset: f32,
-    ) where
-        Renderer: iced::advanced::text::Renderer,
-    {
-        let colors = self.get_theme_colors(theme);
+        Size::new(width, desired_height.min(max_height))
+    }
 
+    fn draw_menu_panel(&self, renderer: &mut Renderer, colors: &ThemeColors, bounds: Rectangle) {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
@@ -199,8 +408,43 @@ where
             },
             colors.background,
         );
+    }
+
+    fn draw_menu_highlight(
+        &self,
+        renderer: &mut Renderer,
+        colors: &ThemeColors,
+        bounds: Rectangle,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 2.0,
+                    y: bounds.y,
+                    width: bounds.width - 4.0,
+                    height: bounds.height,
+                },
+                border: iced::Border::default(),
+                shadow: iced::Shadow::default(),
+            },
+            colors.hover_background,
+        );
+    }
+
+    fn draw_context_menu(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+        is_submenu_open: bool,
+    ) where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let colors = self.get_theme_colors(theme);
+
+        self.draw_menu_panel(renderer, &colors, bounds);
 
-        // Calculate scrollable content area
         let content_bounds = Rectangle {
             x: bounds.x + 8.0,
             y: bounds.y + 8.0,
@@ -208,21 +452,16 @@ where
             height: bounds.height - 16.0,
         };
 
-        let mut y_offset = content_bounds.y - scroll_offset;
-        let item_height = 30.0;
+        let mut y_offset = content_bounds.y;
+        let item_height = self.menu_item_height();
         let padding_x = 12.0;
 
-        // Only draw items that are visible in the scrollable area
-        let visible_top = content_bounds.y;
-        let visible_bottom = content_bounds.y + content_bounds.height;
-
         // Current column item - "Hide [Column]"
-        if y_offset + item_height >= visible_top && y_offset <= visible_bottom {
+        if self.is_hideable {
             let item_bounds = Rectangle {
-                x: bounds.x,
-                y: y_offset.max(visible_top),
-                width: bounds.width,
-                height: item_height.min(visible_bottom - y_offset.max(visible_top)),
+                y: y_offset,
+                height: item_height,
+                ..bounds
             };
 
             let can_hide_current = self.can_hide_column(&self.column_id);
@@ -230,51 +469,30 @@ where
 
             // Highlight on hover (only if clickable)
             if can_hide_current && cursor.is_over(item_bounds) {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: item_bounds.x + 2.0,
-                            y: item_bounds.y,
-                            width: item_bounds.width - 4.0,
-                            height: item_bounds.height,
-                        },
-                        border: iced::Border::default(),
-                        shadow: iced::Shadow::default(),
-                    },
-                    colors.hover_background,
-                );
+                self.draw_menu_highlight(renderer, &colors, item_bounds);
             }
 
-            // Draw text for current column
-            let hide_text = format!("Hide {}", self.column_title);
+            let hide_text = (self.localization.hide_column)(&self.column_title);
             renderer.fill_text(
                 iced::advanced::text::Text {
-                    content: hide_text,
                     bounds: Size::new(item_bounds.width - padding_x * 2.0, item_height),
-                    size: iced::Pixels(14.0),
-                    line_height: iced::advanced::text::LineHeight::Relative(1.2),
-                    font: renderer.default_font(),
-                    align_x: iced::advanced::text::Alignment::Left,
-                    align_y: iced::alignment::Vertical::Center,
-                    wrapping: iced::advanced::text::Wrapping::Word,
-                    shaping: iced::advanced::text::Shaping::Basic,
+                    ..self.menu_text(renderer, hide_text)
                 },
-                Point::new(item_bounds.x + padding_x, y_offset + 14.0),
+                Point::new(item_bounds.x + padding_x, y_offset + item_height / 2.0),
                 current_text_color,
-                content_bounds, // Use content bounds for clipping
+                content_bounds,
             );
-        }
 
-        y_offset += item_height;
+            y_offset += item_height;
+        }
 
         // Draw separator if there are other columns
-        if !self.other_columns.is_empty() && y_offset >= visible_top && y_offset <= visible_bottom {
-            let separator_y = y_offset + 2.0;
+        if self.has_separator() {
             renderer.fill_quad(
                 renderer::Quad {
                     bounds: Rectangle {
                         x: bounds.x + 8.0,
-                        y: separator_y,
+                        y: y_offset + 2.0,
                         width: bounds.width - 16.0,
                         height: 1.0,
                     },
@@ -286,6 +504,74 @@ where
             y_offset += 6.0;
         }
 
+        // The entry opening the submenu of the other columns, highlighted while it is open
+        if !self.other_columns.is_empty() {
+            let item_bounds = Rectangle {
+                y: y_offset,
+                height: item_height,
+                ..bounds
+            };
+
+            if is_submenu_open || cursor.is_over(item_bounds) {
+                self.draw_menu_highlight(renderer, &colors, item_bounds);
+            }
+
+            renderer.fill_text(
+                iced::advanced::text::Text {
+                    bounds: Size::new(item_bounds.width - padding_x * 2.0, item_height),
+                    ..self.menu_text(renderer, self.localization.columns.clone())
+                },
+                Point::new(item_bounds.x + padding_x, y_offset + item_height / 2.0),
+                colors.text,
+                content_bounds,
+            );
+
+            renderer.fill_text(
+                iced::advanced::text::Text {
+                    bounds: Size::new(item_height, item_height),
+                    align_x: iced::advanced::text::Alignment::Right,
+                    ..self.menu_text(renderer, String::from("▸"))
+                },
+                Point::new(
+                    item_bounds.x + item_bounds.width - padding_x,
+                    y_offset + item_height / 2.0,
+                ),
+                colors.text,
+                content_bounds,
+            );
+        }
+    }
+
+    fn draw_submenu(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+        scroll_offset: f32,
+    ) where
+        Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    {
+        let colors = self.get_theme_colors(theme);
+
+        self.draw_menu_panel(renderer, &colors, bounds);
+
+        // Calculate scrollable content area
+        let content_bounds = Rectangle {
+            x: bounds.x + 8.0,
+            y: bounds.y + 8.0,
+            width: bounds.width - 16.0,
+            height: bounds.height - 16.0,
+        };
+
+        let mut y_offset = content_bounds.y - scroll_offset;
+        let item_height = self.menu_item_height();
+        let padding_x = 12.0;
+
+        // Only draw items that are visible in the scrollable area
+        let visible_top = content_bounds.y;
+        let visible_bottom = content_bounds.y + content_bounds.height;
+
         // Draw other columns with checkmarks
         for (column_id, title, visible) in &self.other_columns {
             if y_offset + item_height >= visible_top && y_offset <= visible_bottom {
@@ -306,25 +592,11 @@ where
 
                 // Draw hover highlight if clickable
                 if can_toggle && cursor.is_over(item_bounds) {
-                    let hover_rect = Rectangle {
-                        x: item_bounds.x + 2.0,
-                        y: item_bounds.y,
-                        width: item_bounds.width - 4.0,
-                        height: item_bounds.height,
-                    };
-                    renderer.fill_quad(
-                        renderer::Quad {
-                            bounds: hover_rect,
-                            border: iced::Border::default(),
-                            shadow: iced::Shadow::default(),
-                        },
-                        colors.hover_background,
-                    );
+                    self.draw_menu_highlight(renderer, &colors, item_bounds);
                 }
 
-                // Draw checkbox and text (same as before but with theme colors)
                 let checkbox_size = 16.0;
-                let checkbox_x = item_bounds.x + padding_x;  
+                let checkbox_x = item_bounds.x + padding_x;
                 let checkbox_y = y_offset + (item_height - checkbox_size) / 2.0;
 
                 // Draw the checkbox
@@ -368,23 +640,16 @@ where
 
                 // Draw the column title
                 let gap_between = 8.0;
-                let text_x = checkbox_x + checkbox_size + gap_between;  
-                let left_padding = text_x - bounds.x;     
+                let text_x = checkbox_x + checkbox_size + gap_between;
+                let left_padding = text_x - bounds.x;
                 let text_clip_width = bounds.width - left_padding - padding_x;
 
                 renderer.fill_text(
                     iced::advanced::text::Text {
-                        content: title.to_owned(),
                         bounds: Size::new(text_clip_width, item_height),
-                        size: iced::Pixels(14.0),
-                        line_height: iced::advanced::text::LineHeight::Relative(1.0),
-                        font: renderer.default_font(),
-                        align_x: iced::advanced::text::Alignment::Left,
-                        align_y: iced::alignment::Vertical::Center,
-                        wrapping: iced::advanced::text::Wrapping::Word,
-                        shaping: iced::advanced::text::Shaping::Basic,
+                        ..self.menu_text(renderer, title.to_owned())
                     },
-                    Point::new(text_x, y_offset + 14.0),
+                    Point::new(text_x, y_offset + item_height / 2.0),
                     item_text_color,
                     content_bounds,
                 );
@@ -398,52 +663,112 @@ where
         &self,
         cursor_position: Point,
         menu_bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let relative_y = cursor_position.y - menu_bounds.y - 8.0;
+
+        // Clicked on current column
+        if self.is_hideable
+            && (0.0..self.menu_item_height()).contains(&relative_y)
+            && self.can_hide_column(&self.column_id)
+        {
+            if let Some(on_column_visibility) = &self.on_column_visibility {
+                shell.publish((on_column_visibility)(
+                    ColumnVisibilityMessage::ToggleColumn(self.column_id.clone()),
+                ));
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn handle_submenu_click(
+        &self,
+        cursor_position: Point,
+        submenu_bounds: Rectangle,
         scroll_offset: f32,
         shell: &mut Shell<'_, Message>,
     ) -> bool {
-        if cursor_position.x < menu_bounds.x 
-            || cursor_position.x >= menu_bounds.x + menu_bounds.width
-            || cursor_position.y < menu_bounds.y 
-            || cursor_position.y >= menu_bounds.y + menu_bounds.height {
+        let relative_y = cursor_position.y - submenu_bounds.y - 8.0 + scroll_offset;
+
+        if relative_y < 0.0 {
             return false;
         }
 
-        let relative_y = cursor_position.y - menu_bounds.y - 8.0 + scroll_offset;
-        let item_height = 30.0;
-        let separator_offset = if self.other_columns.is_empty() { 0.0 } else { 6.0 };
-        
-        if relative_y < item_height {
-            // Clicked on current column
-            if self.can_hide_column(&self.column_id) {
+        let other_index = (relative_y / self.menu_item_height()) as usize;
+
+        if let Some((id, _, visible)) = self.other_columns.get(other_index) {
+            let can_toggle = if *visible {
+                self.can_hide_column(id)
+            } else {
+                true
+            };
+
+            if can_toggle {
                 if let Some(on_column_visibility) = &self.on_column_visibility {
-                    shell.publish((on_column_visibility)(ColumnVisibilityMessage::ToggleColumn(self.column_id.clone())));
+                    shell.publish((on_column_visibility)(
+                        ColumnVisibilityMessage::ToggleColumn(id.clone()),
+                    ));
                     return true;
                 }
             }
-        } else if !self.other_columns.is_empty() && relative_y > item_height + separator_offset {
-            // Clicked on other column
-            let other_column_y = relative_y - item_height - separator_offset;
-            let other_index = (other_column_y / item_height) as usize;
-            
-            if let Some((id, _, visible)) = self.other_columns.get(other_index) {
-                let can_toggle = if *visible {
-                    self.can_hide_column(id)
-                } else {
-                    true
-                };
-
-                if can_toggle {
-                    if let Some(on_column_visibility) = &self.on_column_visibility {
-                        shell.publish((on_column_visibility)(ColumnVisibilityMessage::ToggleColumn(id.clone())));
-                        return true;
-                    }
-                }
-            }
         }
 
         false
     }
 
+    // Draws the current width of the column next to its divider
+    fn draw_width_label(&self, renderer: &mut Renderer, theme: &Theme, bounds: Rectangle)
+    where
+        Renderer: iced::advanced::text::Renderer,
+    {
+        use iced::advanced::text::Paragraph;
+
+        let appearance = theme.resize_label(&self.style);
+
+        let text = iced::advanced::text::Text {
+            content: (self.localization.resize_width)(bounds.width),
+            bounds: Size::INFINITY,
+            size: iced::Pixels(12.0),
+            line_height: iced::advanced::text::LineHeight::Relative(1.2),
+            font: renderer.default_font(),
+            align_x: iced::advanced::text::Alignment::Left,
+            align_y: iced::alignment::Vertical::Top,
+            wrapping: iced::advanced::text::Wrapping::None,
+            shaping: iced::advanced::text::Shaping::Basic,
+        };
+
+        let text_size = Renderer::Paragraph::with_text(text.as_ref()).min_bounds();
+        let padding = Vector::new(6.0, 2.0);
+
+        let label_bounds = Rectangle {
+            x: (bounds.x + bounds.width - self.width - text_size.width - padding.x * 2.0 - 4.0)
+                .max(bounds.x),
+            y: bounds.center_y() - text_size.height / 2.0 - padding.y,
+            width: text_size.width + padding.x * 2.0,
+            height: text_size.height + padding.y * 2.0,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: label_bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            appearance
+                .background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+
+        renderer.fill_text(
+            text,
+            label_bounds.position() + padding,
+            appearance.text_color.unwrap_or(Color::WHITE),
+            label_bounds,
+        );
+    }
+
     fn get_theme_colors(&self, theme: &Theme) -> ThemeColors {
         let header_style = theme.header(&self.style);
         let divider_style = theme.divider(&self.style, false);
@@ -470,7 +795,7 @@ impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Divider<'a, Message, Theme, Renderer>
 where
     Message: Clone,
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: style::Catalog,
 {
     fn tag(&self) -> widget::tree::Tag {
@@ -524,12 +849,69 @@ where
         let divider_hover_bounds = self.divider_hover_bounds(layout.bounds());
 
         // Always update hover state for smooth transitions
-        state.is_divider_hovered = cursor.is_over(divider_hover_bounds);
+        state.is_divider_hovered = self.is_resizable && cursor.is_over(divider_hover_bounds);
+
+        if state.drag_origin.is_some() {
+            self.drag.report(Axis::Horizontal);
+        }
+
+        if let event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = *modifiers;
+        }
+
+        // Resize a focused column with the keyboard, as if its divider was dragged
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modifiers,
+            ..
+        }) = event
+        {
+            let offset = match key {
+                keyboard::key::Named::ArrowLeft => -self.key_step,
+                keyboard::key::Named::ArrowRight => self.key_step,
+                _ => 0.0,
+            };
+
+            if offset != 0.0 && modifiers.alt() && state.is_focused && self.is_resizable {
+                if let Some(on_drag_start) = self.on_drag_start.clone() {
+                    shell.publish(on_drag_start);
+                }
+
+                shell.publish((self.on_drag)(offset, false));
+                shell.publish(Message::clone(&self.on_release));
+                shell.capture_event();
+                return;
+            }
+        }
+
+        // The header scrolls under a still cursor while autoscrolling, so follow the
+        // translated cursor rather than waiting for it to move
+        if let event::Event::Window(window::Event::RedrawRequested(_)) = event {
+            let drag = state
+                .drag_origin
+                .zip(cursor.position())
+                .filter(|(_, position)| *position != state.drag_position);
+
+            if let Some((origin, position)) = drag {
+                state.drag_position = position;
+                shell.publish((self.on_drag)((position - origin).x, state.modifiers.alt()));
+                shell.invalidate_layout();
+                shell.invalidate_widgets();
+            }
+        }
 
         // Handle mouse events
         if let event::Event::Mouse(mouse_event) = event {
             match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                    // Clicking a header focuses it for resizing with the keyboard
+                    let is_focused = self.is_resizable && cursor.is_over(layout.bounds());
+
+                    if is_focused != state.is_focused {
+                        state.is_focused = is_focused;
+                        shell.request_redraw();
+                    }
+
                     // Always close context menu on left click anywhere
                     if state.show_context_menu {
                         state.show_context_menu = false;
@@ -537,19 +919,33 @@ where
                         shell.invalidate_widgets();
                     }
                     
-                    if let Some(origin) = cursor.position_over(divider_hover_bounds) {
+                    if let Some(origin) = cursor
+                        .position_over(divider_hover_bounds)
+                        .filter(|_| self.is_resizable)
+                    {
                         state.drag_origin = Some(origin);
+                        state.drag_position = origin;
+
+                        if let Some(on_drag_start) = self.on_drag_start.clone() {
+                            shell.publish(on_drag_start);
+                        }
+
                         shell.invalidate_layout();
                         shell.invalidate_widgets();
+                        shell.capture_event();
                         return;
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Right) => {
                     // Show context menu on right click (only if column visibility is enabled)
-                    if self.on_column_visibility.is_some() && cursor.is_over(layout.bounds()) {
+                    let has_items = self.is_hideable || !self.other_columns.is_empty();
+
+                    if self.on_column_visibility.is_some()
+                        && has_items
+                        && cursor.is_over(layout.bounds())
+                    {
                         if let Some(position) = cursor.position() {
-                            state.context_menu_position = position;
-                            state.show_context_menu = true;
+                            state.open_context_menu(position);
                             shell.invalidate_layout();
                             shell.invalidate_widgets();
                             return;
@@ -558,14 +954,18 @@ where
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     if state.drag_origin.take().is_some() {
-                        shell.publish(self.on_release.clone());
+                        shell.publish(Message::clone(&self.on_release));
                         return;
                     }
                 }
                 mouse::Event::CursorMoved { .. } => {
                     if let Some(position) = cursor.position() {
                         if let Some(origin) = state.drag_origin {
-                            shell.publish((self.on_drag)((position - origin).x));
+                            state.drag_position = position;
+                            shell.publish((self.on_drag)(
+                                (position - origin).x,
+                                state.modifiers.alt(),
+                            ));
                             shell.invalidate_layout();
                             shell.invalidate_widgets();
                             return;
@@ -646,13 +1046,17 @@ where
         let should_show_divider = self.always_show_divider || 
             self.is_content_hovered(layout.bounds(), cursor) ||
             state.is_divider_hovered ||
-            state.drag_origin.is_some();
+            state.drag_origin.is_some() ||
+            state.is_focused;
 
         if should_show_divider {
-            let appearance = theme.divider(
-                &self.style,
-                state.is_divider_hovered || state.drag_origin.is_some(),
-            );
+            let hovered =
+                state.is_divider_hovered || state.drag_origin.is_some() || state.is_focused;
+
+            let appearance = match self.variant {
+                Some(variant) => theme.divider_variant(&self.style, variant, hovered),
+                None => theme.divider(&self.style, hovered),
+            };
 
             let snap = |bounds: Rectangle| {
                 let position = bounds.position();
@@ -676,6 +1080,24 @@ where
                     .unwrap_or_else(|| Color::TRANSPARENT.into()),
             );
         }
+
+        if self.show_width && state.drag_origin.is_some() {
+            self.draw_width_label(renderer, theme, layout.bounds());
+        }
+
+        if self.debug {
+            debug::outline(renderer, layout.bounds(), debug::COLUMN);
+
+            if self.is_resizable {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.divider_hover_bounds(layout.bounds()),
+                        ..Default::default()
+                    },
+                    debug::GRAB_ZONE,
+                );
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -694,8 +1116,6 @@ where
                 divider: self,
                 position: state.context_menu_position + translation,
                 tree,
-                scroll_offset: 0.0,
-                needs_scroll: false,
             };
 
             Some(overlay::Element::new(Box::new(menu_overlay)))
@@ -718,6 +1138,21 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        if self.is_resizable {
+            operation.focusable(None, layout.bounds(), tree.state.downcast_mut::<State>());
+        }
+
+        let has_items = self.is_hideable || !self.other_columns.is_empty();
+
+        // Let OpenColumnMenu find the menu of this column
+        if self.on_column_visibility.is_some() && has_items {
+            operation.custom(
+                Some(&widget::Id::from(self.column_id.clone())),
+                layout.bounds(),
+                tree.state.downcast_mut::<State>(),
+            );
+        }
+
         self.content.as_widget().operate(
             &mut tree.children[0],
             layout.children().next().unwrap(),
@@ -731,7 +1166,7 @@ impl<'a, Message, Theme, Renderer> From<Divider<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: Clone + 'a,
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer + 'a,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
     Theme: style::Catalog + 'a,
 {
     fn from(divider: Divider<'a, Message, Theme, Renderer>) -> Self {
@@ -742,42 +1177,73 @@ where
 // Context menu overlay implementation
 struct ContextMenuOverlay<'a, 'b, Message, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: style::Catalog,
 {
     divider: &'a Divider<'a, Message, Theme, Renderer>,
     position: Point,
     tree: &'b mut widget::Tree,
-    scroll_offset: f32,
-    needs_scroll: bool,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> ContextMenuOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: style::Catalog,
+{
+    fn state(&mut self) -> &mut State {
+        self.tree.state.downcast_mut::<State>()
+    }
+
+    // The child panel of the menu, laid out while it is open
+    fn submenu_bounds(layout: Layout<'_>) -> Option<Rectangle> {
+        layout.children().next().map(|layout| layout.bounds())
+    }
 }
 
 impl<'a, 'b, Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
     for ContextMenuOverlay<'a, 'b, Message, Theme, Renderer>
 where
     Message: Clone,
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: style::Catalog,
 {
-    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
-        let (menu_bounds, needs_scroll) = self.divider.context_menu_bounds(self.position, bounds.height);
-        self.needs_scroll = needs_scroll;
-        
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let menu_size = self.divider.context_menu_size(renderer);
+
         // Ensure menu doesn't go off screen
-        let adjusted_x = if menu_bounds.x + menu_bounds.width > bounds.width {
-            bounds.width - menu_bounds.width
-        } else {
-            menu_bounds.x
-        }.max(0.0);
-        
-        let adjusted_y = if menu_bounds.y + menu_bounds.height > bounds.height {
-            bounds.height - menu_bounds.height
+        let menu_position = Point::new(
+            self.position.x.min(bounds.width - menu_size.width).max(0.0),
+            self.position
+                .y
+                .min(bounds.height - menu_size.height)
+                .max(0.0),
+        );
+
+        let is_submenu_open = self.state().is_submenu_open;
+
+        if !is_submenu_open || self.divider.other_columns.is_empty() {
+            return layout::Node::new(menu_size).move_to(menu_position);
+        }
+
+        let submenu_size = self.divider.submenu_size(renderer, bounds.height);
+
+        // Opens to the right of the menu, or to its left when there is no room
+        let right = menu_position.x + menu_size.width;
+
+        let x = if right + submenu_size.width > bounds.width {
+            (menu_position.x - submenu_size.width).max(0.0)
         } else {
-            menu_bounds.y
-        }.max(0.0);
+            right
+        };
+        let y = (menu_position.y + self.divider.submenu_entry_y() - 8.0)
+            .min(bounds.height - submenu_size.height)
+            .max(0.0);
 
-        layout::Node::new(Size::new(menu_bounds.width, menu_bounds.height))
-            .move_to(Point::new(adjusted_x, adjusted_y))
+        // Children are positioned relative to their parent
+        let submenu = layout::Node::new(submenu_size)
+            .move_to(Point::new(x - menu_position.x, y - menu_position.y));
+
+        layout::Node::with_children(menu_size, vec![submenu]).move_to(menu_position)
     }
 
     fn update(
@@ -785,29 +1251,60 @@ where
         event: &iced::Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        renderer: &Renderer,
-        clipboard: &mut dyn Clipboard,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) {
         let menu_bounds = layout.bounds();
+        let submenu_bounds = Self::submenu_bounds(layout);
+        let entry_bounds = (!self.divider.other_columns.is_empty())
+            .then(|| self.divider.submenu_entry_bounds(menu_bounds));
+
+        let is_over_submenu = submenu_bounds.is_some_and(|bounds| cursor.is_over(bounds));
+        let is_over_entry = entry_bounds.is_some_and(|bounds| cursor.is_over(bounds));
 
         match &event {
             iced::Event::Mouse(mouse_event) => {
                 match mouse_event {
                     mouse::Event::ButtonPressed(mouse::Button::Left) => {
                         if let Some(cursor_pos) = cursor.position() {
-                            if cursor.is_over(menu_bounds) {
-                                if self.divider.handle_context_menu_click(cursor_pos, menu_bounds, self.scroll_offset, shell) {
-                                    let state = self.tree.state.downcast_mut::<State>();
-                                    state.show_context_menu = false;
+                            if let Some(submenu_bounds) = submenu_bounds.filter(|_| is_over_submenu)
+                            {
+                                let scroll_offset = self.state().menu_scroll;
+
+                                if self.divider.handle_submenu_click(
+                                    cursor_pos,
+                                    submenu_bounds,
+                                    scroll_offset,
+                                    shell,
+                                ) {
+                                    self.state().show_context_menu = false;
+                                }
+                                shell.invalidate_layout();
+                                shell.invalidate_widgets();
+                                shell.capture_event();
+                                return;
+                            } else if is_over_entry {
+                                // Clicking the entry opens the submenu as well, e.g. on touch screens
+                                self.state().is_submenu_open = true;
+                                shell.invalidate_layout();
+                                shell.invalidate_widgets();
+                                shell.capture_event();
+                                return;
+                            } else if cursor.is_over(menu_bounds) {
+                                if self.divider.handle_context_menu_click(
+                                    cursor_pos,
+                                    menu_bounds,
+                                    shell,
+                                ) {
+                                    self.state().show_context_menu = false;
                                 }
                                 shell.invalidate_layout();
                                 shell.invalidate_widgets();
                                 shell.capture_event();
                                 return;
                             } else {
-                                let state = self.tree.state.downcast_mut::<State>();
-                                state.show_context_menu = false;
+                                self.state().show_context_menu = false;
                                 shell.capture_event();
                                 return;
                             }
@@ -815,28 +1312,44 @@ where
                     }
                     mouse::Event::ButtonPressed(mouse::Button::Right) => {
                         // Close on right click
-                        let state = self.tree.state.downcast_mut::<State>();
-                        state.show_context_menu = false;
+                        self.state().show_context_menu = false;
                         shell.invalidate_layout();
                         shell.invalidate_widgets();
                         shell.capture_event();
                         return;
                     }
                     mouse::Event::CursorMoved { .. } => {
+                        // Hovering the entry opens the submenu, while hovering the other
+                        // entries closes it. Leaving the menu keeps it as is, so the cursor
+                        // can cross over to the submenu
+                        if is_over_entry {
+                            self.state().is_submenu_open = true;
+                        } else if cursor.is_over(menu_bounds) {
+                            self.state().is_submenu_open = false;
+                        }
+
                         // Always capture mouse moves for hover updates
                         shell.invalidate_layout();
                         shell.invalidate_widgets();
                         return;
                     }
-                    mouse::Event::WheelScrolled { delta } if self.needs_scroll && cursor.is_over(menu_bounds) => {
-                        match delta {
-                            mouse::ScrollDelta::Lines { y, .. } => {
-                                self.scroll_offset = (self.scroll_offset - y * 30.0).max(0.0);
-                            }
-                            mouse::ScrollDelta::Pixels { y, .. } => {
-                                self.scroll_offset = (self.scroll_offset - y).max(0.0);
-                            }
-                        }
+                    mouse::Event::WheelScrolled { delta } if is_over_submenu => {
+                        let Some(submenu_bounds) = submenu_bounds else {
+                            return;
+                        };
+
+                        let max_scroll = (self.divider.submenu_content_height() + 16.0
+                            - submenu_bounds.height)
+                            .max(0.0);
+
+                        let delta = match delta {
+                            mouse::ScrollDelta::Lines { y, .. } => y * 30.0,
+                            mouse::ScrollDelta::Pixels { y, .. } => *y,
+                        };
+
+                        let state = self.state();
+                        state.menu_scroll = (state.menu_scroll - delta).clamp(0.0, max_scroll);
+
                         shell.invalidate_layout();
                         shell.invalidate_widgets();
                         shell.capture_event();
@@ -853,10 +1366,13 @@ where
         &self,
         layout: Layout<'_>,
         cursor: Cursor,
-        viewport: &Rectangle,
-        renderer: &Renderer,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if cursor.is_over(layout.bounds()) {
+        let is_over = cursor.is_over(layout.bounds())
+            || Self::submenu_bounds(layout).is_some_and(|bounds| cursor.is_over(bounds));
+
+        if is_over {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -867,15 +1383,38 @@ where
         &self,
         renderer: &mut Renderer,
         theme: &Theme,
-        style: &renderer::Style,
+        _style: &renderer::Style,
         layout: Layout<'_>,
         cursor: Cursor,
     ) {
-        self.divider.draw_context_menu(renderer, theme, layout.bounds(), cursor, self.scroll_offset);
+        let state = self.tree.state.downcast_ref::<State>();
+        let submenu_bounds = Self::submenu_bounds(layout);
+
+        self.divider.draw_context_menu(
+            renderer,
+            theme,
+            layout.bounds(),
+            cursor,
+            submenu_bounds.is_some(),
+        );
+
+        if let Some(submenu_bounds) = submenu_bounds {
+            // Drawn above the menu, which it may overlap near the edges of the window
+            renderer.with_layer(submenu_bounds, |renderer| {
+                self.divider.draw_submenu(
+                    renderer,
+                    theme,
+                    submenu_bounds,
+                    cursor,
+                    state.menu_scroll,
+                );
+            });
+        }
     }
 
     fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
         layout.bounds().contains(cursor_position)
+            || Self::submenu_bounds(layout).is_some_and(|bounds| bounds.contains(cursor_position))
     }
 }
 
@@ -887,4 +1426,62 @@ struct ThemeColors {
     hover_background: iced::Background,
     disabled_text: Color,
     separator: Color,
-}
\ No newline at end of file
+}
+// Opens the context menu of a column within the target header scrollable, below its title
+pub(crate) struct OpenColumnMenu {
+    target: widget::Id,
+    column: widget::Id,
+    is_target: bool,
+    skip_children: bool,
+    done: bool,
+}
+
+impl OpenColumnMenu {
+    pub fn new(target: widget::Id, column: widget::Id) -> Self {
+        Self {
+            target,
+            column,
+            is_target: false,
+            skip_children: false,
+            done: false,
+        }
+    }
+}
+
+impl Operation for OpenColumnMenu {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if !self.done && !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+        _state: &mut dyn widget::operation::Scrollable,
+    ) {
+        self.is_target = id == Some(&self.target);
+        self.skip_children = !self.is_target;
+    }
+
+    fn custom(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        state: &mut dyn std::any::Any,
+    ) {
+        if !self.is_target || id != Some(&self.column) {
+            return;
+        }
+
+        if let Some(state) = state.downcast_mut::<State>() {
+            // Like a right click, the position is within the scrolled header
+            state.open_context_menu(Point::new(bounds.x, bounds.y + bounds.height));
+
+            self.done = true;
+        }
+    }
+}
@@ -0,0 +1,623 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::text::{self, Text};
+use iced::advanced::widget::operation::Operation;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Overlay, Shell};
+use iced::mouse::Cursor;
+use iced::widget::text_input::{self, TextInput};
+use iced::{
+    alignment, event, keyboard, mouse, overlay, touch, Color, Element, Length, Padding, Point,
+    Rectangle, Size, Vector,
+};
+
+use crate::body;
+use crate::style;
+use crate::sync::ScrollTo;
+
+const BAR_WIDTH: f32 = 260.0;
+const BAR_MARGIN: f32 = 8.0;
+// Room left for the match count at the right edge of the bar
+const COUNT_WIDTH: f32 = 56.0;
+
+/// Returns each row matching the lowercased query, along with the visible position of
+/// its matching cells.
+pub(crate) type Search<'a> = Box<dyn Fn(&str) -> Vec<(usize, Vec<usize>)> + 'a>;
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+    query: String,
+    // The rows matching the query & the visible position of their matching cells
+    matches: Vec<(usize, Vec<usize>)>,
+    current: Option<usize>,
+    // Whether the body is yet to highlight the current match
+    is_highlight_pending: bool,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    fn close(&mut self) {
+        self.is_open = false;
+        self.query.clear();
+        self.matches.clear();
+        self.current = None;
+        self.is_highlight_pending = true;
+    }
+
+    fn search(&mut self, search: &dyn Fn(&str) -> Vec<(usize, Vec<usize>)>) {
+        self.matches = if self.query.is_empty() {
+            vec![]
+        } else {
+            search(&self.query.to_lowercase())
+        };
+    }
+
+    // Moves to the next matching row, or the previous one, wrapping around
+    fn advance(&mut self, backwards: bool) {
+        let rows = || self.matches.iter().map(|(row, _)| *row);
+
+        let next = match self.current {
+            Some(current) if backwards => rows().rev().find(|row| *row < current).or(rows().last()),
+            Some(current) => rows().find(|row| *row > current).or(rows().next()),
+            None if backwards => rows().last(),
+            None => rows().next(),
+        };
+
+        if next.is_some() {
+            self.current = next;
+            self.is_highlight_pending = true;
+        }
+    }
+
+    // The visible position of the matching cells of the current row
+    fn current_cells(&self) -> Vec<usize> {
+        self.matches
+            .iter()
+            .find(|(row, _)| Some(*row) == self.current)
+            .map(|(_, cells)| cells.clone())
+            .unwrap_or_default()
+    }
+
+    fn count(&self) -> String {
+        match self.current {
+            Some(current) => format!(
+                "{}/{}",
+                self.matches
+                    .iter()
+                    .position(|(row, _)| *row == current)
+                    .map_or(0, |i| i + 1),
+                self.matches.len()
+            ),
+            None if self.query.is_empty() => String::new(),
+            None => self.matches.len().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Input {
+    Changed(String),
+    Submitted,
+}
+
+/// Wraps the table and displays a find bar on Ctrl+F, jumping to the rows
+/// matching its query.
+pub(crate) struct Find<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    body: widget::Id,
+    search: Search<'a>,
+    placeholder: String,
+    style: <Theme as style::Catalog>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Find<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        body: widget::Id,
+        search: impl Fn(&str) -> Vec<(usize, Vec<usize>)> + 'a,
+        style: <Theme as style::Catalog>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            body,
+            search: Box::new(search),
+            placeholder: String::new(),
+            style,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: String) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    // Highlights the row in the body & scrolls it into the middle of the viewport
+    fn highlight(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        row: Option<usize>,
+        cells: Vec<usize>,
+    ) {
+        let mut highlight = Highlight {
+            row,
+            cells,
+            geometry: None,
+        };
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut highlight,
+        );
+
+        if let Some((y, height)) = highlight.geometry {
+            let mut scroll = ScrollTo::new(self.body.clone(), y + height / 2.0);
+
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                &mut scroll,
+            );
+        }
+    }
+}
+
+// The text input of the bar, rebuilt from the query of the state
+fn input<'a, Theme, Renderer>(placeholder: &str, query: &str) -> Element<'a, Input, Theme, Renderer>
+where
+    Theme: text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    TextInput::new(placeholder, query)
+        .on_input(Input::Changed)
+        .on_submit(Input::Submitted)
+        .width(Length::Fill)
+        .padding(Padding {
+            top: 4.0,
+            right: COUNT_WIDTH,
+            bottom: 4.0,
+            left: 8.0,
+        })
+        .into()
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Find<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer,
+    Theme: style::Catalog + text_input::Catalog,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(&self.content),
+            widget::Tree::new(&input::<Theme, Renderer>(&self.placeholder, "")),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The rows may have changed along with the view, so an ongoing search is run again
+        if state.is_open {
+            state.search(&self.search);
+        }
+
+        let input = input::<Theme, Renderer>(&self.placeholder, &state.query);
+
+        tree.children[0].diff(&self.content);
+        tree.children[1].diff(&input);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = *modifiers;
+        }
+
+        // Clicking outside of the table closes the bar
+        if let event::Event::Mouse(mouse::Event::ButtonPressed(_))
+        | event::Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if state.is_open && !cursor.is_over(layout.bounds()) {
+                state.close();
+                shell.request_redraw();
+            }
+        }
+
+        // The bar only edits its state, so the body catches up with it here
+        if std::mem::take(&mut state.is_highlight_pending) {
+            let (row, cells) = (state.current, state.current_cells());
+
+            self.highlight(tree, layout, renderer, row, cells);
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(c),
+            modifiers,
+            ..
+        }) = event
+        {
+            if c.as_str() == "f"
+                && modifiers.command()
+                && !shell.is_event_captured()
+                && cursor.is_over(layout.bounds())
+            {
+                tree.state.downcast_mut::<State>().is_open = true;
+
+                let input = tree.children[1]
+                    .state
+                    .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+                input.focus();
+                input.select_all();
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let widget::Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        let (content_tree, input_tree) = children.split_at_mut(1);
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut content_tree[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        );
+
+        if !state.is_open {
+            return content;
+        }
+
+        let bounds = layout.bounds();
+        let width = BAR_WIDTH.min(bounds.width - BAR_MARGIN * 2.0).max(0.0);
+
+        let bar = overlay::Element::new(Box::new(Bar {
+            input: input(&self.placeholder, &state.query),
+            tree: &mut input_tree[0],
+            state,
+            search: &self.search,
+            style: &self.style,
+            position: Point::new(
+                bounds.x + bounds.width - width - BAR_MARGIN,
+                bounds.y + BAR_MARGIN,
+            ) + translation,
+            width,
+        }));
+
+        Some(overlay::Group::with_children(content.into_iter().chain([bar]).collect()).overlay())
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Find<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + text::Renderer + 'a,
+    Theme: style::Catalog + text_input::Catalog + 'a,
+{
+    fn from(find: Find<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(find)
+    }
+}
+
+/// The find bar, floating at the top right corner of the table.
+///
+/// Its text input only captures the keys it handles while focused, so the table & its
+/// cells keep the keyboard once something else is clicked.
+struct Bar<'a, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    input: Element<'a, Input, Theme, Renderer>,
+    tree: &'a mut widget::Tree,
+    state: &'a mut State,
+    search: &'a Search<'a>,
+    style: &'a <Theme as style::Catalog>::Style,
+    position: Point,
+    width: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for Bar<'a, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer,
+    Theme: style::Catalog + text_input::Catalog,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
+        let input = self.input.as_widget().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(self.width, f32::INFINITY)),
+        );
+
+        layout::Node::with_children(input.size(), vec![input]).move_to(self.position)
+    }
+
+    fn update(
+        &mut self,
+        event: &iced::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_focused = self
+            .tree
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        // Escape closes the bar, rather than only unfocusing its input
+        if let iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+            ..
+        }) = event
+        {
+            if is_focused {
+                self.state.close();
+
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+        }
+
+        let mut messages = vec![];
+        let mut input_shell = Shell::new(&mut messages);
+
+        self.input.as_widget_mut().update(
+            self.tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &layout.bounds(),
+        );
+
+        if input_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        shell.request_redraw_at(input_shell.redraw_request());
+        shell.request_input_method(input_shell.input_method());
+
+        for message in messages {
+            match message {
+                Input::Changed(query) => {
+                    self.state.query = query;
+                    self.state.current = None;
+                    self.state.search(self.search);
+                }
+                Input::Submitted => {
+                    let backwards = self.state.modifiers.shift();
+
+                    self.state.advance(backwards);
+                }
+            }
+
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.input.as_widget().mouse_interaction(
+            &*self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        self.input.as_widget().draw(
+            &*self.tree,
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+
+        let text_color = theme
+            .header(self.style)
+            .text_color
+            .unwrap_or(style.text_color);
+
+        renderer.fill_text(
+            Text {
+                content: self.state.count(),
+                bounds: Size::new(COUNT_WIDTH - 8.0, bounds.height),
+                size: renderer.default_size(),
+                line_height: text::LineHeight::default(),
+                font: renderer.default_font(),
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                wrapping: text::Wrapping::None,
+                shaping: text::Shaping::Advanced,
+            },
+            Point::new(bounds.x + bounds.width - 8.0, bounds.center_y()),
+            Color {
+                a: 0.7,
+                ..text_color
+            },
+            bounds,
+        );
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+}
+
+// Sets the highlighted row of the body & reads its geometry
+struct Highlight {
+    row: Option<usize>,
+    cells: Vec<usize>,
+    geometry: Option<(f32, f32)>,
+}
+
+impl Operation for Highlight {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        state: &mut dyn std::any::Any,
+    ) {
+        if let Some(state) = state.downcast_mut::<body::State>() {
+            state.highlight = self.row;
+            state.highlight_cells = std::mem::take(&mut self.cells);
+            self.geometry = self.row.and_then(|row| state.rows.get(row).copied());
+        }
+    }
+}
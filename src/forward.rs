@@ -0,0 +1,141 @@
+/// Implements the listed [`Widget`](iced::advanced::widget::Widget) methods of a wrapper by
+/// forwarding them to its `content`.
+///
+/// Unlike the wrappers of the [`style`](crate::style) module, which share the tree & layout
+/// of their content, these keep a state of their own & lay out their content as their only
+/// child. Only the methods the wrapper doesn't need to intercept should be listed.
+macro_rules! forward {
+    ($($method:ident),* $(,)?) => {
+        $($crate::forward::forward!(@ $method);)*
+    };
+    (@ children) => {
+        fn children(&self) -> Vec<iced::advanced::widget::Tree> {
+            vec![iced::advanced::widget::Tree::new(&self.content)]
+        }
+    };
+    (@ diff) => {
+        fn diff(&self, tree: &mut iced::advanced::widget::Tree) {
+            tree.diff_children(&[&self.content]);
+        }
+    };
+    (@ size) => {
+        fn size(&self) -> iced::Size<iced::Length> {
+            self.content.as_widget().size()
+        }
+    };
+    (@ layout) => {
+        fn layout(
+            &self,
+            tree: &mut iced::advanced::widget::Tree,
+            renderer: &Renderer,
+            limits: &iced::advanced::layout::Limits,
+        ) -> iced::advanced::layout::Node {
+            let content = self
+                .content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits);
+
+            iced::advanced::layout::Node::with_children(content.size(), vec![content])
+        }
+    };
+    (@ update) => {
+        fn update(
+            &mut self,
+            tree: &mut iced::advanced::widget::Tree,
+            event: &iced::Event,
+            layout: iced::advanced::Layout<'_>,
+            cursor: iced::mouse::Cursor,
+            renderer: &Renderer,
+            clipboard: &mut dyn iced::advanced::Clipboard,
+            shell: &mut iced::advanced::Shell<'_, Message>,
+            viewport: &iced::Rectangle,
+        ) {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    };
+    (@ mouse_interaction) => {
+        fn mouse_interaction(
+            &self,
+            tree: &iced::advanced::widget::Tree,
+            layout: iced::advanced::Layout<'_>,
+            cursor: iced::mouse::Cursor,
+            viewport: &iced::Rectangle,
+            renderer: &Renderer,
+        ) -> iced::mouse::Interaction {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    };
+    (@ draw) => {
+        fn draw(
+            &self,
+            tree: &iced::advanced::widget::Tree,
+            renderer: &mut Renderer,
+            theme: &Theme,
+            style: &iced::advanced::renderer::Style,
+            layout: iced::advanced::Layout<'_>,
+            cursor: iced::mouse::Cursor,
+            viewport: &iced::Rectangle,
+        ) {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+    };
+    (@ overlay) => {
+        fn overlay<'b>(
+            &'b mut self,
+            tree: &'b mut iced::advanced::widget::Tree,
+            layout: iced::advanced::Layout<'_>,
+            renderer: &Renderer,
+            viewport: &iced::Rectangle,
+            translation: iced::Vector,
+        ) -> Option<iced::overlay::Element<'b, Message, Theme, Renderer>> {
+            self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                viewport,
+                translation,
+            )
+        }
+    };
+    (@ operate) => {
+        fn operate(
+            &self,
+            tree: &mut iced::advanced::widget::Tree,
+            layout: iced::advanced::Layout<'_>,
+            renderer: &Renderer,
+            operation: &mut dyn iced::advanced::widget::Operation,
+        ) {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        }
+    };
+}
+
+pub(crate) use forward;
@@ -0,0 +1,222 @@
+//! Parse CSV & TSV text into rows of strings.
+
+/// Rows of text parsed by [`parse`] or [`parse_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Data {
+    /// The header row, if one was detected or requested.
+    pub header: Option<Vec<String>>,
+    /// The remaining rows.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Parses delimited text, detecting its delimiter & whether it starts with a header row.
+///
+/// See [`sniff_delimiter`] & [`has_header`] for the heuristics used.
+pub fn parse(text: &str) -> Data {
+    let delimiter = sniff_delimiter(text);
+    let rows = split(text, delimiter);
+    let header = has_header(&rows);
+
+    into_data(rows, header)
+}
+
+/// Parses delimited text with the given delimiter, treating its first row as a header when
+/// `header` is true.
+pub fn parse_with(text: &str, delimiter: char, header: bool) -> Data {
+    into_data(split(text, delimiter), header)
+}
+
+/// Returns the delimiter of the given text among tabs, commas, semicolons & pipes.
+///
+/// The delimiter appearing the same, non-zero number of times on the most lines wins.
+/// Defaults to a comma.
+pub fn sniff_delimiter(text: &str) -> char {
+    const CANDIDATES: [char; 4] = ['\t', ',', ';', '|'];
+
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.is_empty())
+        .take(10)
+        .collect();
+
+    CANDIDATES
+        .into_iter()
+        .map(|delimiter| {
+            let counts: Vec<usize> = lines
+                .iter()
+                .map(|line| line.matches(delimiter).count())
+                .collect();
+
+            let first = counts.first().copied().unwrap_or_default();
+            let consistent = counts.iter().filter(|count| **count == first).count();
+
+            (delimiter, if first > 0 { consistent } else { 0 })
+        })
+        .filter(|(_, score)| *score > 0)
+        .max_by_key(|(_, score)| *score)
+        .map_or(',', |(delimiter, _)| delimiter)
+}
+
+/// Returns whether the first of the given rows looks like a header.
+///
+/// It does when its fields are all distinct, non-empty & non-numeric while another row
+/// contains a number.
+pub fn has_header(rows: &[Vec<String>]) -> bool {
+    let Some((first, rest)) = rows.split_first() else {
+        return false;
+    };
+
+    let is_numeric = |field: &String| field.trim().parse::<f64>().is_ok();
+
+    let is_distinct = first
+        .iter()
+        .enumerate()
+        .all(|(index, field)| !first[..index].contains(field));
+
+    is_distinct
+        && first
+            .iter()
+            .all(|field| !field.trim().is_empty() && !is_numeric(field))
+        && rest.iter().any(|row| row.iter().any(is_numeric))
+}
+
+fn into_data(mut rows: Vec<Vec<String>>, header: bool) -> Data {
+    let header = (header && !rows.is_empty()).then(|| rows.remove(0));
+
+    Data { header, rows }
+}
+
+// Splits the text into rows of fields, honoring double quoted fields
+//
+// A quote only opens a quoted field at the start of a field, elsewhere it is kept as is,
+// e.g. `5" floppy` stays a single field.
+fn split(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                // Doubled quotes escape a quote within a quoted field
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            c if in_quotes => field.push(c),
+            c if c == delimiter => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    // Drop blank lines, e.g. trailing ones
+    rows.retain(|row| !(row.len() == 1 && row[0].is_empty()));
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|field| field.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn split_quoted_delimiters_and_doubled_quotes() {
+        assert_eq!(
+            split("\"a,b\",\"say \"\"hi\"\"\"\n", ','),
+            rows(&[&["a,b", "say \"hi\""]])
+        );
+    }
+
+    #[test]
+    fn split_quote_within_field_is_literal() {
+        assert_eq!(
+            split("5\" floppy,\"3\"\" disk\"", ','),
+            rows(&[&["5\" floppy", "3\" disk"]])
+        );
+    }
+
+    #[test]
+    fn split_crlf() {
+        assert_eq!(
+            split("a,b\r\nc,d\r\n", ','),
+            rows(&[&["a", "b"], &["c", "d"]])
+        );
+    }
+
+    #[test]
+    fn split_trailing_newline() {
+        assert_eq!(split("a,b\n", ','), split("a,b", ','));
+        assert_eq!(split("a,b\n", ','), rows(&[&["a", "b"]]));
+    }
+
+    #[test]
+    fn split_blank_lines() {
+        assert_eq!(
+            split("a,b\n\nc,d\n\n\n", ','),
+            rows(&[&["a", "b"], &["c", "d"]])
+        );
+    }
+
+    #[test]
+    fn split_quoted_newline() {
+        assert_eq!(
+            split("\"line 1\nline 2\",b\nc,d", ','),
+            rows(&[&["line 1\nline 2", "b"], &["c", "d"]])
+        );
+    }
+
+    #[test]
+    fn sniff_delimiter_ragged() {
+        assert_eq!(sniff_delimiter("a,b,c\nd,e\nf,g,h\n"), ',');
+        assert_eq!(sniff_delimiter("a\tb\tc\nd\te,f\ng\th\ti\n"), '\t');
+        assert_eq!(sniff_delimiter("a\nb\n"), ',');
+    }
+
+    #[test]
+    fn has_header_with_numbers() {
+        assert!(has_header(&rows(&[&["name", "age"], &["Ada", "36"]])));
+        assert!(!has_header(&rows(&[&["Ada", "36"], &["Bob", "41"]])));
+    }
+
+    #[test]
+    fn has_header_all_text() {
+        assert!(!has_header(&rows(&[
+            &["name", "city"],
+            &["Ada", "London"],
+            &["Bob", "Paris"],
+        ])));
+    }
+
+    #[test]
+    fn parse_detects_header() {
+        assert_eq!(
+            parse("name;age\r\nAda;36\r\n"),
+            Data {
+                header: Some(vec![String::from("name"), String::from("age")]),
+                rows: rows(&[&["Ada", "36"]]),
+            }
+        );
+    }
+}
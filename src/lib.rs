@@ -1,34 +1,93 @@
 //! A table widget for iced
 #![deny(missing_debug_implementations, missing_docs)]
-pub use style::Catalog;
-pub use table::{table, Table};
+pub use columns::{ColumnLayout, Columns};
+pub use confirm::{confirm_button, ConfirmButton};
+pub use link::{link_cell, Link};
+pub use localization::Localization;
+pub use measure::{measure_ch_width, measure_text_column};
+pub use pivot::{pivot_table, Pivot};
+pub use presets::{computed_column, currency_column, datetime_column, unit_column};
+pub use show_more::{show_more, ShowMore};
+pub use style::{Catalog, DividerVariant, Separator};
+pub use table::{status_text, table, Table};
+pub use tags::{tags_cell, Tags};
+pub use views::Views;
+pub use widths::WidthStore;
 
+pub mod columns;
+pub mod import;
+pub mod pivot;
+pub mod presets;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
+pub mod views;
+
+mod body;
+mod budget;
+mod confirm;
+mod debug;
 mod divider;
+mod find;
+mod forward;
+mod link;
+mod localization;
+mod measure;
+mod notify;
+mod overflow;
+mod reorder;
+mod row_resize;
+mod show_more;
+mod slide;
 mod style;
+mod sync;
+mod tags;
+mod widths;
 
 pub use divider::ColumnVisibilityMessage;
 
 pub mod table {
     //! Display rows of data into columns
-    use iced::{Element, Length, Padding};
-    use iced::widget::{column, container, row, scrollable, Space};
+    use iced::advanced::widget;
+    use iced::alignment;
+    use iced::widget::{
+        column, container, hover, responsive, row, scrollable, text, tooltip, Space,
+    };
+    use iced::{Element, Length, Padding, Task};
+
+    use std::ops::Range;
+    use std::path::PathBuf;
+    use std::rc::Rc;
 
-    use super::divider::Divider;
+    use super::body::{Body, FlashRow, FocusedRow, MAX_SCALE, MIN_SCALE};
+    use super::budget::{Budget, Deferred, DeferredRow};
+    use super::columns::MIN_COLUMN_WIDTH;
+    use super::debug::{self, Outline};
+    use super::divider::{Divider, OpenColumnMenu};
+    use super::find::Find;
+    use super::localization::Localization;
+    use super::notify::Notify;
+    use super::overflow::{self, Overflow};
+    use super::reorder::Reorder;
+    use super::row_resize::RowResize;
+    use super::slide::Slide;
     use super::style;
+    use super::sync::{Drag, ScrollSync};
 
     /// Creates a new [`Table`] with the provided [`Column`] definitions
     /// and [`Row`](Column::Row) data.
     ///
-    /// `on_sync` is needed to keep the header & footer scrollables in sync with
-    /// the body scrollable. It is up to the consumer to emit a [`scroll_to`](iced_widget::scrollable::scroll_to) operation
-    /// from `update` when this message is received.
-    pub fn table<'a, Column, Row, Message, Theme>(
+    /// The header & footer scrollables are kept in sync with the body scrollable
+    /// within the same frame. `on_sync` is still emitted with the horizontal offset of
+    /// the body whenever it scrolls, but it is no longer necessary to emit a
+    /// [`scroll_to`](iced_widget::scrollable::scroll_to) operation from `update` when
+    /// this message is received.
+    pub fn table<'a, Column, Row, Message, Theme, Renderer>(
         header: scrollable::Id,
         body: scrollable::Id,
         columns: &'a [Column],
         rows: &'a [Row],
         on_sync: fn(scrollable::AbsoluteOffset) -> Message,
-    ) -> Table<'a, Column, Row, Message, Theme>
+    ) -> Table<'a, Column, Row, Message, Theme, Renderer>
     where
         Theme: style::Catalog + container::Catalog,
     {
@@ -41,16 +100,91 @@ pub mod table {
             on_sync,
             on_column_drag: None,
             on_column_release: None,
+            on_column_resize_start: None,
             on_column_visibility: None,
+            on_column_reorder: None,
+            on_file_drop: None,
+            on_visible_rows: None,
+            on_paste_rows: None,
+            on_row_toggle: None,
+            on_row_resize: None,
+            on_header_resize: None,
+            on_zoom: None,
+            row_heights: &[],
+            auto_row_height: false,
+            row_hover_actions: None,
+            merge_duplicates: Vec::new(),
             min_width: 0.0,
-            min_column_width: 4.0,
+            min_column_width: MIN_COLUMN_WIDTH,
+            resize_step: None,
+            show_resize_width: false,
+            read_only: false,
+            debug: false,
+            context_menu_font: None,
+            context_menu_text_size: iced::Pixels(14.0),
+            localization: Localization::default(),
             divider_width: 2.0,
             cell_padding: 4.into(),
+            header_height: Length::Shrink,
+            footer_height: Length::Shrink,
+            find_bar: false,
+            overflow_menu: false,
+            responsive_columns: false,
             style: Default::default(),
-            scrollbar: scrollable::Scrollbar::default(),
+            horizontal_scrollbar: Some(scrollable::Scrollbar::default()),
+            vertical_scrollbar: Some(scrollable::Scrollbar::default()),
+            height: Length::Fill,
+            max_height: None,
+            corner_radius: 0.0,
+            scale: 1.0,
+            reduce_motion: false,
+            render_budget: None,
+            toolbar: None,
+            status_bar: None,
         }
     }
 
+    /// Scrolls the row at `index` of the [`Table`] using the `body` [`scrollable::Id`] into
+    /// view and briefly flashes it with the [`row_highlight`](crate::Catalog::row_highlight)
+    /// style.
+    ///
+    /// This is useful to point the user at a row that was just created or updated.
+    pub fn flash_row<Message>(body: scrollable::Id, index: usize) -> Task<Message>
+    where
+        Message: Send + 'static,
+    {
+        widget::operate(FlashRow::new(widget::Id::from(body), index)).discard()
+    }
+
+    /// Opens the column menu of the [`Table`] using the `header` [`scrollable::Id`], anchored
+    /// below the header of the column identified by `column_id`.
+    ///
+    /// This is the same menu shown by right clicking a header, e.g. to open it from a
+    /// "Columns" button of a toolbar. Nothing happens if the table doesn't handle
+    /// [`on_column_visibility`](Table::on_column_visibility).
+    pub fn open_column_menu<Message>(
+        header: scrollable::Id,
+        column_id: impl Into<String>,
+    ) -> Task<Message>
+    where
+        Message: Send + 'static,
+    {
+        widget::operate(OpenColumnMenu::new(
+            widget::Id::from(header),
+            widget::Id::from(column_id.into()),
+        ))
+        .discard()
+    }
+
+    /// Summarizes the rows of a [`Table`] for its [`status_bar`](Table::status_bar), e.g.
+    /// "1,204 rows • 3 selected • filtered from 8,911".
+    ///
+    /// The selection is omitted when `selected` is 0 and the filter when `rows` equals `total`.
+    /// Use [`Localization::status_text`] for other languages.
+    pub fn status_text(rows: usize, selected: usize, total: usize) -> String {
+        Localization::default().status_text(rows, selected, total)
+    }
+
     /// Defines what a column looks like for each [`Row`](Self::Row) of data.
     pub trait Column<'a, Message, Theme, Renderer> {
         /// A row of data.
@@ -68,6 +202,9 @@ pub mod table {
         ) -> Element<'a, Message, Theme, Renderer>;
 
         /// Define the footer [`Element`] for this column.
+        ///
+        /// Columns returning `None` leave their footer cell empty. When every visible column
+        /// returns `None`, the footer is not shown at all.
         fn footer(
             &'a self,
             _col_index: usize,
@@ -76,6 +213,18 @@ pub mod table {
             None
         }
 
+        /// Define a compact summary [`Element`] shown beneath the header of this column, e.g.
+        /// a sum or average of its values.
+        ///
+        /// This is useful when there is no vertical space left for a footer.
+        fn header_summary(
+            &'a self,
+            _col_index: usize,
+            _rows: &'a [Self::Row],
+        ) -> Option<Element<'a, Message, Theme, Renderer>> {
+            None
+        }
+
         /// Return the fixed width for this column.
         fn width(&self) -> f32;
 
@@ -83,26 +232,99 @@ pub mod table {
         fn resize_offset(&self) -> Option<f32>;
 
         /// Return the unique identifier for this column (used for visibility tracking).
-        fn id(&self) -> String {
-            // Default implementation - users should override this with meaningful IDs
-            format!("column_{}", std::any::type_name::<Self>())
+        ///
+        /// The default is derived from `col_index`, so it follows the column's position
+        /// rather than the column itself. Override it with a stable id when columns can be
+        /// reordered, or their layout is persisted.
+        ///
+        /// Previously, this method took no index and defaulted to the type name of the column,
+        /// which was shared by every column of the same type. Implementations can migrate by
+        /// adding the `_col_index: usize` argument & ignoring it.
+        fn id(&self, col_index: usize) -> String {
+            format!("column_{col_index}")
         }
 
         /// Return the display title for this column (used in context menu).
+        ///
+        /// Columns with an empty title are displayed as
+        /// [`Localization::untitled_column`].
         fn title(&self) -> String {
-            // Default implementation - users should override this with meaningful titles
-            format!("Column")
+            String::new()
         }
 
         /// Return whether this column is currently visible.
         fn is_visible(&self) -> bool {
             true
         }
+
+        /// Return whether this column is listed in the column visibility context menu.
+        ///
+        /// Columns that return `false` can never be hidden or shown by the user.
+        fn hideable(&self) -> bool {
+            true
+        }
+
+        /// Return the text of this column for the given row, searched by the find bar and
+        /// listed by the overflow menu.
+        ///
+        /// See [`Table::find_bar`] & [`Table::overflow_menu`].
+        fn search_text(&self, _row: &Self::Row) -> Option<String> {
+            None
+        }
+
+        /// Return the tooltip shown while hovering the cell of this column for the given row.
+        ///
+        /// The tooltip wraps the whole cell, so it doesn't get in the way of its width.
+        fn cell_tooltip(&self, _row: &Self::Row) -> Option<String> {
+            None
+        }
+
+        /// Return the value compared between consecutive rows to merge duplicate cells.
+        ///
+        /// See [`Table::merge_duplicates`].
+        fn merge_key(&self, _row: &Self::Row) -> Option<String> {
+            None
+        }
+
+        /// Return the priority of this column when space runs out.
+        ///
+        /// With [`Table::responsive_columns`], columns with the lowest priority are hidden
+        /// first. Ties are broken by hiding the rightmost column first.
+        fn priority(&self) -> u8 {
+            0
+        }
+
+        /// Return the [`DividerVariant`](crate::DividerVariant) of the divider of this column,
+        /// if it should stand out from the others.
+        fn divider_style(&self) -> Option<super::style::DividerVariant> {
+            None
+        }
+
+        /// Return whether this column can be resized by dragging its divider.
+        ///
+        /// Only applies when resizing is enabled with [`Table::on_column_resize`].
+        fn resizable(&self) -> bool {
+            true
+        }
+    }
+
+    /// The computed horizontal bounds of a [`Column`] of a [`Table`].
+    ///
+    /// See [`Table::column_bounds`].
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct ColumnBounds {
+        /// The index of the column, or `None` for the filler added to reach the
+        /// [`min_width`](Table::min_width) of the table.
+        pub index: Option<usize>,
+        /// The offset of the column from the left edge of the table.
+        pub x: f32,
+        /// The width of the column, including its divider.
+        pub width: f32,
     }
 
     /// An element to display rows of data into columns.
     #[allow(missing_debug_implementations)]
-    pub struct Table<'a, Column, Row, Message, Theme>
+    pub struct Table<'a, Column, Row, Message, Theme, Renderer = iced::Renderer>
     where
         Theme: style::Catalog + container::Catalog,
     {
@@ -114,16 +336,50 @@ pub mod table {
         on_sync: fn(scrollable::AbsoluteOffset) -> Message,
         on_column_drag: Option<fn(usize, f32) -> Message>,
         on_column_release: Option<Message>,
+        on_column_resize_start: Option<fn(usize) -> Message>,
         on_column_visibility: Option<fn(super::divider::ColumnVisibilityMessage) -> Message>,
+        on_column_reorder: Option<fn(usize, usize) -> Message>,
+        on_file_drop: Option<fn(Vec<PathBuf>, Option<usize>) -> Message>,
+        on_visible_rows: Option<fn(Range<usize>) -> Message>,
+        on_paste_rows: Option<fn(Vec<Vec<String>>) -> Message>,
+        on_row_toggle: Option<fn(usize, bool) -> Message>,
+        on_row_resize: Option<fn(usize, f32) -> Message>,
+        on_header_resize: Option<fn(f32) -> Message>,
+        on_zoom: Option<fn(f32) -> Message>,
+        row_heights: &'a [f32],
+        auto_row_height: bool,
+        row_hover_actions: Option<fn(usize, &'a Row) -> Vec<Element<'a, Message, Theme, Renderer>>>,
+        merge_duplicates: Vec<usize>,
         min_width: f32,
         min_column_width: f32,
+        resize_step: Option<f32>,
+        show_resize_width: bool,
+        read_only: bool,
+        debug: bool,
+        context_menu_font: Option<iced::Font>,
+        context_menu_text_size: iced::Pixels,
+        localization: Localization,
         divider_width: f32,
         cell_padding: Padding,
+        header_height: Length,
+        footer_height: Length,
+        find_bar: bool,
+        overflow_menu: bool,
+        responsive_columns: bool,
         style: <Theme as style::Catalog>::Style,
-        scrollbar: scrollable::Scrollbar,
+        horizontal_scrollbar: Option<scrollable::Scrollbar>,
+        vertical_scrollbar: Option<scrollable::Scrollbar>,
+        height: Length,
+        max_height: Option<f32>,
+        corner_radius: f32,
+        scale: f32,
+        reduce_motion: bool,
+        render_budget: Option<usize>,
+        toolbar: Option<Element<'a, Message, Theme, Renderer>>,
+        status_bar: Option<Element<'a, Message, Theme, Renderer>>,
     }
 
-    impl<'a, Column, Row, Message, Theme> Table<'a, Column, Row, Message, Theme>
+    impl<'a, Column, Row, Message, Theme, Renderer> Table<'a, Column, Row, Message, Theme, Renderer>
     where
         Theme: style::Catalog + container::Catalog,
     {
@@ -147,8 +403,21 @@ pub mod table {
             }
         }
 
+        /// Sets the message that will be produced when the resize of a [`Column`] starts.
+        ///
+        /// Only applies when resizing is enabled with [`Table::on_column_resize`].
+        pub fn on_resize_start(self, on_resize_start: fn(usize) -> Message) -> Self {
+            Self {
+                on_column_resize_start: Some(on_resize_start),
+                ..self
+            }
+        }
+
         /// Sets the message that will be produced when column visibility is changed.
         /// This enables the right-click context menu for hiding/showing columns.
+        ///
+        /// The menu hides the clicked column, while the other columns are toggled from its
+        /// [`columns`](Localization::columns) submenu, opened by hovering or clicking it.
         pub fn on_column_visibility(
             self,
             on_visibility: fn(super::divider::ColumnVisibilityMessage) -> Message,
@@ -159,7 +428,150 @@ pub mod table {
             }
         }
 
+        /// Sets the message that will be produced when a [`Column`] header is dragged to a new
+        /// position. Setting this will enable the reordering interaction.
+        ///
+        /// `on_reorder` receives the index of the dragged column and the index it should be moved
+        /// to, mirroring a [`Vec::remove`] followed by a [`Vec::insert`]. It is up to the consumer
+        /// to reorder the columns passed to [`table`].
+        pub fn on_column_reorder(self, on_reorder: fn(usize, usize) -> Message) -> Self {
+            Self {
+                on_column_reorder: Some(on_reorder),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when files are dropped onto the body of the
+        /// [`Table`].
+        ///
+        /// `on_file_drop` receives every file of the drop along with the index of the row under
+        /// the cursor, if any.
+        pub fn on_file_drop(
+            self,
+            on_file_drop: fn(Vec<PathBuf>, Option<usize>) -> Message,
+        ) -> Self {
+            Self {
+                on_file_drop: Some(on_file_drop),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the range of rows visible within the
+        /// body of the [`Table`] changes.
+        ///
+        /// This is useful to lazily load the content of rows as they are scrolled into view.
+        pub fn on_visible_rows(self, on_visible_rows: fn(Range<usize>) -> Message) -> Self {
+            Self {
+                on_visible_rows: Some(on_visible_rows),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when tabular text is pasted with Ctrl+V into
+        /// the body of the [`Table`], once its rows were clicked.
+        ///
+        /// The clipboard is parsed with the [`import`](crate::import) module, e.g. cells copied
+        /// from a spreadsheet. Several lines of a single column count as tabular, while a
+        /// single line without a delimiter doesn't. Pastes handled by a cell, like a text
+        /// input, are ignored, and clicking anywhere else in the window hands pasting back to
+        /// the rest of it.
+        pub fn on_paste_rows(self, on_paste_rows: fn(Vec<Vec<String>>) -> Message) -> Self {
+            Self {
+                on_paste_rows: Some(on_paste_rows),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when Space is pressed on the focused row of
+        /// the [`Table`], to toggle its selection.
+        ///
+        /// `on_row_toggle` receives the index of the row and whether Shift was held, to
+        /// extend the selection up to the row instead. A row is focused by clicking one of its
+        /// cells, and moves along with the navigation keys.
+        pub fn on_row_toggle(self, on_row_toggle: fn(usize, bool) -> Message) -> Self {
+            Self {
+                on_row_toggle: Some(on_row_toggle),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the bottom edge of a row is dragged.
+        /// Setting this will enable the row resizing interaction.
+        ///
+        /// `on_row_resize` receives the index of the row and its new height. It is up to the
+        /// consumer to store it and pass it back with [`Table::row_heights`].
+        pub fn on_row_resize(self, on_row_resize: fn(usize, f32) -> Message) -> Self {
+            Self {
+                on_row_resize: Some(on_row_resize),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the bottom edge of the header is
+        /// dragged. Setting this will enable the header resizing interaction.
+        ///
+        /// `on_header_resize` receives the new height of the header. It is up to the consumer
+        /// to store it and pass it back with [`Table::header_height`]. Storing it with
+        /// [`Columns::set_header_height`](crate::Columns::set_header_height) saves it along
+        /// with the [`Views`](crate::Views).
+        pub fn on_header_resize(self, on_header_resize: fn(f32) -> Message) -> Self {
+            Self {
+                on_header_resize: Some(on_header_resize),
+                ..self
+            }
+        }
+
+        /// Sets the fixed heights of the rows of the [`Table`], by index.
+        ///
+        /// Rows past the end of `row_heights` grow to fit their cells. Content taller than
+        /// a fixed height is clipped, unless [`Table::auto_row_height`] is set.
+        pub fn row_heights(self, row_heights: &'a [f32]) -> Self {
+            Self {
+                row_heights,
+                ..self
+            }
+        }
+
+        /// Sets whether every row grows to fit its tallest cell, e.g. the wrapped text of a
+        /// notes column.
+        ///
+        /// While set, the [`Table::row_heights`] are ignored, so no content is clipped, and
+        /// rows can't be resized by dragging their bottom edge.
+        pub fn auto_row_height(self, auto_row_height: bool) -> Self {
+            Self {
+                auto_row_height,
+                ..self
+            }
+        }
+
+        /// Sets the actions shown at the right edge of a row while it is hovered.
+        ///
+        /// `row_hover_actions` receives the index of the row along with its data and returns
+        /// the elements to display, e.g. edit or delete buttons.
+        pub fn row_hover_actions(
+            self,
+            row_hover_actions: fn(usize, &'a Row) -> Vec<Element<'a, Message, Theme, Renderer>>,
+        ) -> Self {
+            Self {
+                row_hover_actions: Some(row_hover_actions),
+                ..self
+            }
+        }
+
+        /// Sets the indices of the columns whose consecutive duplicate cells are merged.
+        ///
+        /// Within these columns, a cell is left blank when its [`Column::merge_key`] matches
+        /// the one of the previous row, so each value is only displayed once per run.
+        pub fn merge_duplicates(self, columns: &[usize]) -> Self {
+            Self {
+                merge_duplicates: columns.to_vec(),
+                ..self
+            }
+        }
+
         /// Show the footer returned by [`Column::footer`].
+        ///
+        /// The footer is omitted when no visible column has a footer to show.
         pub fn footer(self, footer: scrollable::Id) -> Self {
             Self {
                 footer: Some(footer),
@@ -175,7 +587,11 @@ pub mod table {
             Self { min_width, ..self }
         }
 
-        /// Sets the minimum width a column can be resized to.
+        /// Sets the minimum width a column can be resized to, 4 by default.
+        ///
+        /// Pass the same minimum to
+        /// [`Columns::min_column_width`](crate::Columns::min_column_width) so the widths
+        /// stored by [`Columns`](crate::Columns) are clamped alike.
         pub fn min_column_width(self, min_column_width: f32) -> Self {
             Self {
                 min_column_width,
@@ -183,6 +599,69 @@ pub mod table {
             }
         }
 
+        /// Sets the increment the width of a [`Column`] snaps to while it is being resized.
+        ///
+        /// While a step is set, holding Alt snaps the width to the width of the closest
+        /// visible column instead.
+        ///
+        /// A header focused by clicking it can also be resized with Alt+Left & Alt+Right, by
+        /// the step or by 10 pixels without one.
+        pub fn resize_step(self, resize_step: Option<f32>) -> Self {
+            Self {
+                resize_step: resize_step.filter(|step| *step > 0.0),
+                ..self
+            }
+        }
+
+        /// Sets whether the width of a [`Column`] is displayed next to its divider while it
+        /// is being resized.
+        pub fn show_resize_width(self, show_resize_width: bool) -> Self {
+            Self {
+                show_resize_width,
+                ..self
+            }
+        }
+
+        /// Sets whether the [`Table`] is read-only.
+        ///
+        /// A read-only table can't have its columns resized or reordered, nor files dropped or
+        /// rows pasted onto it, while scrolling, focusing rows & hiding columns keep working.
+        pub fn read_only(self, read_only: bool) -> Self {
+            Self { read_only, ..self }
+        }
+
+        /// Sets whether the debug overlay is drawn.
+        ///
+        /// The overlay outlines every column & the filler added to honor
+        /// [`min_width`](Self::min_width), shades the area where each divider can be grabbed
+        /// and shows the scroll offsets of the body.
+        pub fn debug(self, debug: bool) -> Self {
+            Self { debug, ..self }
+        }
+
+        /// Sets the [`Font`](iced::Font) of the column visibility context menu.
+        ///
+        /// Defaults to the default font of the renderer.
+        pub fn context_menu_font(self, context_menu_font: iced::Font) -> Self {
+            Self {
+                context_menu_font: Some(context_menu_font),
+                ..self
+            }
+        }
+
+        /// Sets the text size of the column visibility context menu.
+        ///
+        /// The menu grows to fit its entries, measured with the context menu font & size.
+        pub fn context_menu_text_size(
+            self,
+            context_menu_text_size: impl Into<iced::Pixels>,
+        ) -> Self {
+            Self {
+                context_menu_text_size: context_menu_text_size.into(),
+                ..self
+            }
+        }
+
         /// Sets the width of the column dividers.
         pub fn divider_width(self, divider_width: f32) -> Self {
             Self {
@@ -191,37 +670,609 @@ pub mod table {
             }
         }
 
-        /// Sets the [`Padding`] used inside each cell of the [`Table`].
-        pub fn cell_padding(self, cell_padding: impl Into<Padding>) -> Self {
-            Self {
-                cell_padding: cell_padding.into(),
-                ..self
+        /// Sets the [`Padding`] used inside each cell of the [`Table`].
+        pub fn cell_padding(self, cell_padding: impl Into<Padding>) -> Self {
+            Self {
+                cell_padding: cell_padding.into(),
+                ..self
+            }
+        }
+
+        /// Sets the height of the header of the [`Table`].
+        ///
+        /// Header cells are stretched to a [`Length::Fixed`] height, making room for e.g.
+        /// two-line headers. Other lengths only size the header band itself.
+        pub fn header_height(self, header_height: impl Into<Length>) -> Self {
+            Self {
+                header_height: header_height.into(),
+                ..self
+            }
+        }
+
+        /// Sets the height of the footer of the [`Table`].
+        ///
+        /// Footer cells are stretched to a [`Length::Fixed`] height. Other lengths only size
+        /// the footer band itself.
+        pub fn footer_height(self, footer_height: impl Into<Length>) -> Self {
+            Self {
+                footer_height: footer_height.into(),
+                ..self
+            }
+        }
+
+        /// Sets whether the find bar is enabled.
+        ///
+        /// When enabled, pressing Ctrl+F over the [`Table`] opens a find bar matching the
+        /// [`Column::search_text`] of each visible column. Enter jumps to the next matching
+        /// row & highlights it, Shift+Enter jumps to the previous one and Escape closes it.
+        ///
+        /// The rows are only searched while the bar has a query. Clicking the table hands the
+        /// keyboard back to it & its cells, while clicking outside of it closes the bar.
+        pub fn find_bar(self, find_bar: bool) -> Self {
+            Self { find_bar, ..self }
+        }
+
+        /// Sets whether columns are hidden automatically when the [`Table`] is too narrow.
+        ///
+        /// Hideable columns are hidden by ascending [`Column::priority`] until the remaining
+        /// columns fit the available width, and reappear when space returns. When column
+        /// visibility is enabled, the ids of the hidden columns are emitted with
+        /// [`ColumnVisibilityMessage::AutoHidden`](crate::ColumnVisibilityMessage::AutoHidden)
+        /// whenever they change.
+        pub fn responsive_columns(self, responsive_columns: bool) -> Self {
+            Self {
+                responsive_columns,
+                ..self
+            }
+        }
+
+        /// Sets whether a "⋯" button trails the header while [`Table::responsive_columns`]
+        /// hides columns.
+        ///
+        /// Clicking it opens a menu listing the hidden columns along with their
+        /// [`Column::search_text`] for the focused row, i.e. the row of the last clicked
+        /// cell. Room is made for the button by hiding more columns if needed.
+        pub fn overflow_menu(self, overflow_menu: bool) -> Self {
+            Self {
+                overflow_menu,
+                ..self
+            }
+        }
+
+        /// Sets the [`Localization`] of the built-in text of the [`Table`].
+        pub fn localization(self, localization: Localization) -> Self {
+            Self {
+                localization,
+                ..self
+            }
+        }
+
+        /// Sets the style variant of this [`Table`].
+        pub fn style(self, style: impl Into<<Theme as style::Catalog>::Style>) -> Self {
+            Self {
+                style: style.into(),
+                ..self
+            }
+        }
+
+        ///  Sets the [`Scrollbar`](iced_widget::scrollable::Scrollbar) used for the table's body scrollable.
+        #[deprecated(note = "use `horizontal_scrollbar` and `vertical_scrollbar` instead")]
+        pub fn scrollbar(self, scrollbar: scrollable::Scrollbar) -> Self {
+            Self {
+                horizontal_scrollbar: Some(scrollbar),
+                vertical_scrollbar: Some(scrollbar),
+                ..self
+            }
+        }
+
+        /// Sets the horizontal [`Scrollbar`](iced_widget::scrollable::Scrollbar) of the table's
+        /// body scrollable.
+        ///
+        /// The scrollbar is hidden when `None`, though the body can still be scrolled
+        /// horizontally, e.g. with Shift + wheel.
+        pub fn horizontal_scrollbar(
+            self,
+            horizontal_scrollbar: impl Into<Option<scrollable::Scrollbar>>,
+        ) -> Self {
+            Self {
+                horizontal_scrollbar: horizontal_scrollbar.into(),
+                ..self
+            }
+        }
+
+        /// Sets the vertical [`Scrollbar`](iced_widget::scrollable::Scrollbar) of the table's
+        /// body scrollable.
+        ///
+        /// The scrollbar is hidden when `None`, though the body can still be scrolled
+        /// vertically with the mouse wheel.
+        pub fn vertical_scrollbar(
+            self,
+            vertical_scrollbar: impl Into<Option<scrollable::Scrollbar>>,
+        ) -> Self {
+            Self {
+                vertical_scrollbar: vertical_scrollbar.into(),
+                ..self
+            }
+        }
+
+        /// Sets the height of the [`Table`].
+        ///
+        /// The body scrolls vertically within the space left by the header & footer.
+        pub fn height(self, height: impl Into<Length>) -> Self {
+            Self {
+                height: height.into(),
+                ..self
+            }
+        }
+
+        /// Sets the maximum height of the [`Table`].
+        ///
+        /// A [`Length::Fill`] table placed inside a vertical [`scrollable`] would grow with its
+        /// rows, letting the parent scroll the header away. Capping its height keeps the header
+        /// in place while the body scrolls & clips its rows internally.
+        pub fn max_height(self, max_height: f32) -> Self {
+            Self {
+                max_height: Some(max_height),
+                ..self
+            }
+        }
+
+        /// Sets the radius of the corners of the [`Table`].
+        ///
+        /// The header, body & footer are inset from the rounded corners, so their scrolled
+        /// content never pokes out of them. The gap is filled with the header background.
+        pub fn corner_radius(self, corner_radius: f32) -> Self {
+            Self {
+                corner_radius,
+                ..self
+            }
+        }
+
+        /// Sets the scale of the [`Table`], 1.0 by default.
+        ///
+        /// The widths of the columns & the heights of the rows, along with the paddings,
+        /// dividers & built-in text of the table are multiplied by `scale`. The content of the
+        /// cells is left as is. Resize messages still report unscaled sizes.
+        ///
+        /// The scale is clamped between 0.25 & 4.0, the range of [`Table::on_zoom`].
+        pub fn scale(self, scale: f32) -> Self {
+            Self {
+                scale: scale.clamp(MIN_SCALE, MAX_SCALE),
+                ..self
+            }
+        }
+
+        /// Sets whether the [`Table`] should avoid animations, e.g. to honor the reduced
+        /// motion setting of the OS.
+        ///
+        /// A [flashed](flash_row) row is then highlighted as is for the duration of the flash
+        /// instead of fading out, and the columns following a hidden column jump into place
+        /// instead of sliding to fill its space.
+        pub fn reduce_motion(self, reduce_motion: bool) -> Self {
+            Self {
+                reduce_motion,
+                ..self
+            }
+        }
+
+        /// Sets the maximum number of cells of the [`Table`] built per frame.
+        ///
+        /// Only the cells of the visible rows are built, [`Column::cell`] included, and at
+        /// most `render_budget` of them per frame. The remaining ones are filled in over the
+        /// following frames, with the [`placeholder`](crate::Catalog::placeholder) style
+        /// standing in for them meanwhile. This keeps the table responsive when a huge area
+        /// of tiny rows is visible at once.
+        ///
+        /// Cells that were built keep their layout until the next `view`, so each frame
+        /// only lays out the new ones. Rows more than a screen away from the visible ones
+        /// are left as empty space of the height of a line, or of their
+        /// [`row_heights`](Self::row_heights).
+        pub fn render_budget(self, render_budget: Option<usize>) -> Self {
+            Self {
+                render_budget,
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the body of the [`Table`] is scrolled
+        /// with Ctrl held down.
+        ///
+        /// `on_zoom` receives the new scale, between 0.25 & 4.0, which is up to the consumer
+        /// to pass back with [`Table::scale`].
+        pub fn on_zoom(self, on_zoom: fn(f32) -> Message) -> Self {
+            Self {
+                on_zoom: Some(on_zoom),
+                ..self
+            }
+        }
+
+        /// Sets the toolbar displayed above the header of the [`Table`].
+        ///
+        /// The toolbar spans the width of the table, making it a good fit for search boxes,
+        /// density toggles or export buttons.
+        pub fn toolbar(self, toolbar: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+            Self {
+                toolbar: Some(toolbar.into()),
+                ..self
+            }
+        }
+
+        /// Sets the status bar displayed below the footer of the [`Table`].
+        ///
+        /// See [`status_text`] to summarize the rows of the table.
+        pub fn status_bar(
+            self,
+            status_bar: impl Into<Element<'a, Message, Theme, Renderer>>,
+        ) -> Self {
+            Self {
+                status_bar: Some(status_bar.into()),
+                ..self
+            }
+        }
+    }
+
+    impl<'a, Column, Row, Message, Theme, Renderer> Table<'a, Column, Row, Message, Theme, Renderer>
+    where
+        Theme: style::Catalog + container::Catalog,
+        Message: Clone,
+    {
+        // Elements can't be cloned, so the copy is left without a toolbar & status bar
+        fn duplicate(&self) -> Self {
+            Self {
+                header: self.header.clone(),
+                body: self.body.clone(),
+                footer: self.footer.clone(),
+                columns: self.columns,
+                rows: self.rows,
+                on_sync: self.on_sync,
+                on_column_drag: self.on_column_drag,
+                on_column_release: self.on_column_release.clone(),
+                on_column_resize_start: self.on_column_resize_start,
+                on_column_visibility: self.on_column_visibility,
+                on_column_reorder: self.on_column_reorder,
+                on_file_drop: self.on_file_drop,
+                on_visible_rows: self.on_visible_rows,
+                on_paste_rows: self.on_paste_rows,
+                on_row_toggle: self.on_row_toggle,
+                on_row_resize: self.on_row_resize,
+                on_header_resize: self.on_header_resize,
+                on_zoom: self.on_zoom,
+                row_heights: self.row_heights,
+                auto_row_height: self.auto_row_height,
+                row_hover_actions: self.row_hover_actions,
+                merge_duplicates: self.merge_duplicates.clone(),
+                min_width: self.min_width,
+                min_column_width: self.min_column_width,
+                resize_step: self.resize_step,
+                show_resize_width: self.show_resize_width,
+                read_only: self.read_only,
+                debug: self.debug,
+                context_menu_font: self.context_menu_font,
+                context_menu_text_size: self.context_menu_text_size,
+                localization: self.localization.clone(),
+                divider_width: self.divider_width,
+                cell_padding: self.cell_padding,
+                header_height: self.header_height,
+                footer_height: self.footer_height,
+                find_bar: self.find_bar,
+                overflow_menu: self.overflow_menu,
+                responsive_columns: self.responsive_columns,
+                style: self.style.clone(),
+                horizontal_scrollbar: self.horizontal_scrollbar,
+                vertical_scrollbar: self.vertical_scrollbar,
+                height: self.height,
+                max_height: self.max_height,
+                corner_radius: self.corner_radius,
+                scale: self.scale,
+                reduce_motion: self.reduce_motion,
+                render_budget: self.render_budget,
+                toolbar: None,
+                status_bar: None,
+            }
+        }
+    }
+
+    impl<'a, Column, Row, Message, Theme, Renderer>
+        From<Table<'a, Column, Row, Message, Theme, Renderer>>
+        for Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + iced::widget::text::Catalog
+            + iced::widget::text_input::Catalog
+            + 'a,
+        <Theme as scrollable::Catalog>::Class<'a>: From<scrollable::StyleFn<'a, Theme>>,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        fn from(mut table: Table<'a, Column, Row, Message, Theme, Renderer>) -> Self {
+            let toolbar = table.toolbar.take();
+            let status_bar = table.status_bar.take();
+
+            if toolbar.is_none() && status_bar.is_none() {
+                return table.into_element();
+            }
+
+            let height = std::mem::replace(&mut table.height, Length::Fill);
+
+            let mut column = column![];
+
+            if let Some(toolbar) = toolbar {
+                column = column.push(container(toolbar).width(Length::Fill));
+            }
+
+            column = column.push(table.into_element());
+
+            if let Some(status_bar) = status_bar {
+                column = column.push(container(status_bar).width(Length::Fill));
+            }
+
+            column.height(height).into()
+        }
+    }
+
+    impl<'a, Column, Row, Message, Theme, Renderer> Table<'a, Column, Row, Message, Theme, Renderer>
+    where
+        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+        Theme: style::Catalog
+            + container::Catalog
+            + scrollable::Catalog
+            + iced::widget::text::Catalog
+            + iced::widget::text_input::Catalog
+            + 'a,
+        <Theme as scrollable::Catalog>::Class<'a>: From<scrollable::StyleFn<'a, Theme>>,
+        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        fn into_element(self) -> Element<'a, Message, Theme, Renderer> {
+            if !self.responsive_columns {
+                return self.view(&[]);
+            }
+
+            // Sized like the table itself, rather than filling its parent
+            let height = self.height;
+            let max_height = self.max_height;
+
+            let content = responsive(move |size| {
+                let hidden = self.overflowing_columns(size.width);
+
+                let ids: Vec<String> = hidden
+                    .iter()
+                    .map(|index| self.columns[*index].id(*index))
+                    .collect();
+                let on_change = self.on_column_visibility.map(|on_visibility| {
+                    (on_visibility)(super::divider::ColumnVisibilityMessage::AutoHidden(
+                        ids.clone(),
+                    ))
+                });
+
+                Notify::new(self.duplicate().view(&hidden), ids, on_change).into()
+            })
+            .height(height);
+
+            match max_height {
+                Some(max_height) => container(content).max_height(max_height).into(),
+                None => content.into(),
+            }
+        }
+
+        /// Returns the bounds of each visible [`Column`] once the [`Table`] is laid out within
+        /// the given `width`.
+        ///
+        /// This accounts for [`min_column_width`](Self::min_column_width), ongoing resizes,
+        /// [`responsive_columns`](Self::responsive_columns) & the filler added to reach
+        /// [`min_width`](Self::min_width), so layout invariants can be asserted without
+        /// rendering.
+        pub fn column_bounds(&self, width: f32) -> Vec<ColumnBounds> {
+            self.layout_columns(&self.hidden_columns(width))
+        }
+
+        // The messages of dragging the divider of a column by `offset` & releasing it
+        #[cfg(feature = "test_utils")]
+        pub(crate) fn divider_drag(
+            &self,
+            width: f32,
+            index: usize,
+            offset: f32,
+            snap_to_columns: bool,
+        ) -> Vec<Message> {
+            let hidden = self.hidden_columns(width);
+
+            let Some(((on_drag, on_release), column)) = self
+                .on_column_drag
+                .zip(self.on_column_release.clone())
+                .zip(self.columns.get(index))
+            else {
+                return Vec::new();
+            };
+
+            if self.read_only
+                || !column.resizable()
+                || !column.is_visible()
+                || hidden.contains(&index)
+            {
+                return Vec::new();
+            }
+
+            let widths = self.column_widths();
+            let other_widths: Vec<f32> = self
+                .columns
+                .iter()
+                .enumerate()
+                .filter(|(i, col)| *i != index && col.is_visible() && !hidden.contains(i))
+                .map(|(i, _)| widths[i] / self.scale)
+                .collect();
+
+            let old_width = column.width();
+            let new_width = resized_width(
+                old_width,
+                offset,
+                self.scale,
+                self.resize_step,
+                snap_to_columns,
+                &other_widths,
+                self.min_column_width,
+            );
+
+            self.on_column_resize_start
+                .map(|on_resize_start| (on_resize_start)(index))
+                .into_iter()
+                .chain([(on_drag)(index, new_width - old_width), on_release])
+                .collect()
+        }
+
+        // The message of clicking the entry of the column at `index` in the context menu
+        // opened from the header of the column at `header`
+        #[cfg(feature = "test_utils")]
+        pub(crate) fn menu_toggle(
+            &self,
+            width: f32,
+            header: usize,
+            index: usize,
+        ) -> Option<Message> {
+            let on_visibility = self.on_column_visibility?;
+            let hidden = self.hidden_columns(width);
+
+            self.columns
+                .get(header)
+                .filter(|column| column.is_visible() && !hidden.contains(&header))?;
+            let column = self.columns.get(index).filter(|column| column.hideable())?;
+
+            // The header's own column is hidden from the menu as long as another column
+            // of the menu stays visible, while the others toggle from the submenu
+            let can_toggle = index != header
+                || self
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .any(|(i, other)| i != header && other.hideable() && other.is_visible());
+
+            can_toggle.then(|| {
+                (on_visibility)(super::divider::ColumnVisibilityMessage::ToggleColumn(
+                    column.id(index),
+                ))
+            })
+        }
+
+        // The columns hidden for lack of space once laid out within `width`
+        fn hidden_columns(&self, width: f32) -> Vec<usize> {
+            if self.responsive_columns {
+                self.overflowing_columns(width)
+            } else {
+                vec![]
+            }
+        }
+
+        // The width of every column, shared by the header, body, footer & dividers
+        fn column_widths(&self) -> Vec<f32> {
+            self.columns
+                .iter()
+                .map(|column| {
+                    (column.width() + column.resize_offset().unwrap_or_default())
+                        .max(self.min_column_width)
+                        * self.scale
+                })
+                .collect()
+        }
+
+        fn layout_columns(&self, hidden: &[usize]) -> Vec<ColumnBounds> {
+            let widths = self.column_widths();
+            let mut x = 0.0;
+
+            let mut bounds: Vec<_> = self
+                .columns
+                .iter()
+                .enumerate()
+                .filter(|(index, column)| column.is_visible() && !hidden.contains(index))
+                .map(|(index, _)| {
+                    let width = widths[index];
+
+                    let column = ColumnBounds {
+                        index: Some(index),
+                        x,
+                        width,
+                    };
+
+                    x += width;
+                    column
+                })
+                .collect();
+
+            let min_width = self.min_width * self.scale;
+
+            if min_width > x {
+                bounds.push(ColumnBounds {
+                    index: None,
+                    x,
+                    width: min_width - x,
+                });
             }
+
+            bounds
         }
 
-        /// Sets the style variant of this [`Table`].
-        pub fn style(self, style: impl Into<<Theme as style::Catalog>::Style>) -> Self {
-            Self {
-                style: style.into(),
-                ..self
+        // Returns the indices of the columns to hide so the rest fit within `width`
+        fn overflowing_columns(&self, width: f32) -> Vec<usize> {
+            let widths = self.column_widths();
+
+            let visible: Vec<_> = self
+                .columns
+                .iter()
+                .enumerate()
+                .filter(|(_, column)| column.is_visible())
+                .collect();
+
+            let mut candidates: Vec<_> = visible
+                .iter()
+                .filter(|(_, column)| column.hideable())
+                .collect();
+
+            candidates
+                .sort_by_key(|(index, column)| (column.priority(), std::cmp::Reverse(*index)));
+
+            let mut total: f32 = visible.iter().map(|(index, _)| widths[*index]).sum();
+            let mut hidden = vec![];
+
+            // Hiding a column brings up the overflow menu, which needs room as well
+            let width = if self.overflow_menu && total > width {
+                width - overflow::WIDTH * self.scale
+            } else {
+                width
+            };
+
+            for (index, _) in candidates {
+                // Always keep at least one column around
+                if total <= width || hidden.len() + 1 >= visible.len() {
+                    break;
+                }
+
+                total -= widths[*index];
+                hidden.push(*index);
             }
-        }
 
-        ///  Sets the [`Scrollbar`](iced_widget::scrollable::Scrollbar) used for the table's body scrollable.
-        pub fn scrollbar(self, scrollbar: scrollable::Scrollbar) -> Self {
-            Self { scrollbar, ..self }
+            hidden
         }
-    }
 
-    impl<'a, Column, Row, Message, Theme, Renderer> From<Table<'a, Column, Row, Message, Theme>>
-        for Element<'a, Message, Theme, Renderer>
-    where
-        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer + 'a,
-        Theme: style::Catalog + container::Catalog + scrollable::Catalog + 'a,
-        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
-        Message: 'a + Clone,
-    {
-        fn from(table: Table<'a, Column, Row, Message, Theme>) -> Self {
+        fn view(self, hidden: &[usize]) -> Element<'a, Message, Theme, Renderer> {
+            let is_shown =
+                |index: usize, column: &Column| column.is_visible() && !hidden.contains(&index);
+
+            let widths = self.column_widths();
+
+            let column_bounds = self.layout_columns(hidden);
+
+            let filler = column_bounds
+                .last()
+                .filter(|column| column.index.is_none())
+                .map(|column| column.width);
+
+            // The width of a row, filler included
+            let row_width = column_bounds
+                .last()
+                .map_or(0.0, |column| column.x + column.width);
+
             let Table {
                 header,
                 body,
@@ -231,119 +1282,442 @@ pub mod table {
                 on_sync,
                 on_column_drag,
                 on_column_release,
+                on_column_resize_start,
                 on_column_visibility,
+                on_column_reorder,
+                on_file_drop,
+                on_visible_rows,
+                on_paste_rows,
+                on_row_toggle,
+                on_row_resize,
+                on_header_resize,
+                on_zoom,
+                row_heights,
+                auto_row_height,
+                row_hover_actions,
+                merge_duplicates,
                 min_width,
                 min_column_width,
+                resize_step,
+                show_resize_width,
+                read_only,
+                debug,
+                context_menu_font,
+                context_menu_text_size,
+                localization,
                 divider_width,
                 cell_padding,
+                header_height,
+                footer_height,
+                find_bar,
+                overflow_menu,
+                responsive_columns: _,
                 style,
-                scrollbar,
-            } = table;
+                horizontal_scrollbar,
+                vertical_scrollbar,
+                height,
+                max_height,
+                corner_radius,
+                scale,
+                reduce_motion,
+                render_budget,
+                toolbar: _,
+                status_bar: _,
+            } = self;
+
+            let divider_width = divider_width * scale;
+            let context_menu_text_size = context_menu_text_size * scale;
+            let cell_padding = Padding {
+                top: cell_padding.top * scale,
+                right: cell_padding.right * scale,
+                bottom: cell_padding.bottom * scale,
+                left: cell_padding.left * scale,
+            };
+            let scale_length = |length: Length| match length {
+                Length::Fixed(size) => Length::Fixed(size * scale),
+                length => length,
+            };
+            let header_height = scale_length(header_height);
+            let footer_height = scale_length(footer_height);
+
+            // Shared by every divider & cell, so they don't each clone the message & style
+            let on_column_release = on_column_release.map(Rc::new);
+            let shared_style = Rc::new(style.clone());
+
+            // Lets the dragged dividers, headers & rows autoscroll the body
+            let drag = Drag::default();
+
+            // Lets the remaining columns slide into place once a column is hidden
+            let shown: Vec<(String, f32)> = columns
+                .iter()
+                .enumerate()
+                .filter(|(index, column)| is_shown(*index, column))
+                .map(|(index, column)| (column.id(index), widths[index]))
+                .collect();
+
+            let sync_body = widget::Id::from(body.clone());
+            let sync_targets = std::iter::once(header.clone())
+                .chain(footer.clone())
+                .map(widget::Id::from)
+                .collect();
+
+            let header_row = row(columns
+                .iter()
+                .enumerate()
+                .filter(|(index, column)| is_shown(*index, column))
+                .map(|(index, column)| {
+                    header_container(
+                        index,
+                        column,
+                        columns,
+                        &widths,
+                        hidden,
+                        rows,
+                        on_column_drag,
+                        on_column_release.clone(),
+                        on_column_resize_start,
+                        on_column_visibility.clone(),
+                        min_column_width,
+                        resize_step,
+                        show_resize_width,
+                        read_only,
+                        debug,
+                        context_menu_font,
+                        context_menu_text_size,
+                        &localization,
+                        &drag,
+                        divider_width,
+                        scale,
+                        cell_padding,
+                        cell_height(header_height),
+                        Rc::clone(&shared_style),
+                    )
+                })
+                .chain(dummy_container(filler, debug)));
+
+            let header_row = if let Some(on_reorder) = on_column_reorder.filter(|_| !read_only) {
+                // The drop slots follow the same bounds as the cells, rather than the
+                // layout of the headers
+                let shown_bounds = column_bounds
+                    .into_iter()
+                    .filter(|column| column.index.is_some())
+                    .collect();
+
+                Reorder::new(header_row, shown_bounds, on_reorder, style.clone())
+                    .drag(drag.clone())
+                    .into()
+            } else {
+                Element::from(header_row)
+            };
+
+            let header_row = Slide::new(header_row, shown.clone(), reduce_motion);
+
+            let header = scrollable(style::wrapper::header(header_row, style.clone()))
+                .id(header)
+                .height(header_height)
+                .direction(scrollable::Direction::Both {
+                    vertical: scrollable::Scrollbar::new()
+                        .width(0)
+                        .margin(0)
+                        .scroller_width(0),
+                    horizontal: scrollable::Scrollbar::new()
+                        .width(0)
+                        .margin(0)
+                        .scroller_width(0),
+                });
+
+            let header = match on_header_resize.filter(|_| !read_only) {
+                Some(on_header_resize) => RowResize::new(
+                    header,
+                    move |height| (on_header_resize)(height / scale),
+                    style.clone(),
+                )
+                .into(),
+                None => Element::from(header),
+            };
 
-            let header = scrollable(style::wrapper::header(
-                row(columns
+            // Lets the overflow menu list the values of the focused row
+            let focused_row = FocusedRow::default();
+
+            let header = if overflow_menu && !hidden.is_empty() {
+                let overflowed = hidden.to_vec();
+                let untitled = localization.untitled_column.clone();
+                let menu_style = style.clone();
+
+                let label = move |content: String| {
+                    let label = text(content).size(context_menu_text_size);
+
+                    match context_menu_font {
+                        Some(font) => label.font(font),
+                        None => label,
+                    }
+                };
+
+                let menu = move |focused_row: Option<usize>| {
+                    let row_data = focused_row.and_then(|index| rows.get(index));
+
+                    let entries = column(overflowed.iter().map(|index| {
+                        let column = &columns[*index];
+
+                        let title = Some(column.title())
+                            .filter(|title| !title.is_empty())
+                            .unwrap_or_else(|| untitled.clone());
+                        let value = row_data
+                            .and_then(|row| column.search_text(row))
+                            .unwrap_or_default();
+
+                        row![label(title), label(value)]
+                            .spacing(12.0 * scale)
+                            .into()
+                    }))
+                    .spacing(4.0 * scale);
+
+                    style::wrapper::tooltip(
+                        container(scrollable(entries)).padding(8.0 * scale),
+                        menu_style.clone(),
+                    )
+                };
+
+                let button = container(label(String::from("⋯")))
+                    .center_x(overflow::WIDTH * scale)
+                    .center_y(Length::Fill);
+
+                row![
+                    header,
+                    Overflow::new(button, menu, focused_row.clone(), style.clone())
+                ]
+                .into()
+            } else {
+                header
+            };
+
+            let budget = render_budget.filter(|budget| *budget > 0).map(Budget::new);
+
+            // The shown columns & their width, owned by the rows so they can be built later
+            let row_columns: Vec<(usize, f32)> = columns
+                .iter()
+                .enumerate()
+                .filter(|(index, column)| is_shown(*index, column))
+                .map(|(index, _)| (index, widths[index]))
+                .collect();
+
+            let build_row = {
+                let budget = budget.clone();
+                let shared_style = Rc::clone(&shared_style);
+                let style = style.clone();
+                let drag = drag.clone();
+
+                Rc::new(move |row_index: usize, row_data: &'a Row| {
+                    // The shown columns & the filler
+                    let cells = iced::widget::Row::with_capacity(row_columns.len() + 1).extend(
+                        row_columns
+                            .iter()
+                            .map(|&(col_index, width)| {
+                                let column = &columns[col_index];
+
+                                let is_merged = merge_duplicates.contains(&col_index)
+                                    && row_index
+                                        .checked_sub(1)
+                                        .and_then(|previous| rows.get(previous))
+                                        .is_some_and(|previous| {
+                                            let key = column.merge_key(row_data);
+
+                                            key.is_some() && key == column.merge_key(previous)
+                                        });
+
+                                let cell_style = Rc::clone(&shared_style);
+
+                                let cell = move || {
+                                    body_container(
+                                        col_index,
+                                        row_index,
+                                        column,
+                                        row_data,
+                                        is_merged,
+                                        width,
+                                        divider_width,
+                                        cell_padding,
+                                        &*cell_style,
+                                    )
+                                };
+
+                                match &budget {
+                                    Some(budget) => Deferred::new(
+                                        cell,
+                                        row_index,
+                                        width,
+                                        budget.clone(),
+                                        Rc::clone(&shared_style),
+                                    )
+                                    .into(),
+                                    None => cell(),
+                                }
+                            })
+                            .chain(dummy_container(filler, debug)),
+                    );
+
+                    let cells = match row_heights.get(row_index).filter(|_| !auto_row_height) {
+                        Some(height) => cells.height(*height * scale).clip(true),
+                        None => cells,
+                    };
+
+                    let content = if let Some(row_hover_actions) = row_hover_actions {
+                        let actions =
+                            container(row((row_hover_actions)(row_index, row_data)).spacing(4))
+                                .width(Length::Fill)
+                                .height(Length::Fill)
+                                .padding(cell_padding)
+                                .align_x(alignment::Horizontal::Right)
+                                .align_y(alignment::Vertical::Center);
+
+                        hover(cells, actions)
+                    } else {
+                        Element::from(cells)
+                    };
+
+                    let content = style::wrapper::row(content, style.clone(), row_index);
+
+                    match on_row_resize.filter(|_| !read_only && !auto_row_height) {
+                        Some(on_row_resize) => RowResize::new(
+                            content,
+                            move |height| (on_row_resize)(row_index, height / scale),
+                            style.clone(),
+                        )
+                        .drag(drag.clone())
+                        .into(),
+                        None => content,
+                    }
+                })
+            };
+
+            // With a budget, the rows away from the visible ones are left as spacers, so
+            // only the rows around them hold cells waiting to be built
+            let body_rows = column(rows.iter().enumerate().map(
+                |(row_index, row_data)| match &budget {
+                    Some(budget) => {
+                        let build_row = Rc::clone(&build_row);
+                        let height = row_heights
+                            .get(row_index)
+                            .filter(|_| !auto_row_height)
+                            .map(|height| height * scale);
+
+                        DeferredRow::new(
+                            move || build_row(row_index, row_data),
+                            row_index,
+                            row_width,
+                            height,
+                            budget.clone(),
+                        )
+                        .into()
+                    }
+                    None => build_row(row_index, row_data),
+                },
+            ));
+
+            let body_content = Body::new(body_rows, style.clone())
+                .on_file_drop(on_file_drop.filter(|_| !read_only))
+                .on_visible_rows(on_visible_rows)
+                .on_paste_rows(on_paste_rows.filter(|_| !read_only))
+                .on_row_toggle(on_row_toggle)
+                .on_zoom(on_zoom, scale)
+                .reduce_motion(reduce_motion)
+                .render_budget(budget)
+                .focused_row(focused_row)
+                .debug(debug)
+                .columns(shown.len())
+                .nested_cells(
+                    usize::from(render_budget.is_some_and(|budget| budget > 0))
+                        + usize::from(row_hover_actions.is_some())
+                        + usize::from(on_row_resize.is_some() && !read_only && !auto_row_height),
+                );
+
+            let body = scrollable(Slide::new(body_content, shown.clone(), reduce_motion))
+                .id(body)
+                .on_scroll(move |viewport| {
+                    let offset = viewport.absolute_offset();
+
+                    // Keep header & footer from drifting past the edges of the body content
+                    let max_x =
+                        (viewport.content_bounds().width - viewport.bounds().width).max(0.0);
+
+                    (on_sync)(scrollable::AbsoluteOffset {
+                        x: offset.x.clamp(0.0, max_x),
+                        y: 0.0,
+                    })
+                })
+                .direction(scrollable::Direction::Both {
+                    horizontal: horizontal_scrollbar.unwrap_or_else(hidden_scrollbar),
+                    vertical: vertical_scrollbar.unwrap_or_else(hidden_scrollbar),
+                })
+                .height(Length::Fill)
+                .style({
+                    let style = style.clone();
+
+                    move |theme: &Theme, status| theme.scrollbar(&style, status)
+                });
+
+            let footer = footer.and_then(|footer| {
+                let cells: Vec<_> = columns
                     .iter()
                     .enumerate()
-                    .filter(|(_, column)| column.is_visible())
-                    .map(|(index, column)| {
-                        header_container(
+                    .filter(|(index, column)| is_shown(*index, column))
+                    .map(|(index, column)| (index, column, column.footer(index, rows)))
+                    .collect();
+
+                // Don't reserve any space for a footer that has nothing to show
+                if cells.iter().all(|(_, _, footer)| footer.is_none()) {
+                    return None;
+                }
+
+                let footer_row = row(cells
+                    .into_iter()
+                    .map(|(index, column, content)| {
+                        footer_container(
                             index,
                             column,
                             columns,
+                            &widths,
+                            hidden,
+                            content,
                             on_column_drag,
                             on_column_release.clone(),
+                            on_column_resize_start,
                             on_column_visibility.clone(),
                             min_column_width,
+                            resize_step,
+                            show_resize_width,
+                            read_only,
+                            debug,
+                            context_menu_font,
+                            context_menu_text_size,
+                            &localization,
+                            &drag,
                             divider_width,
+                            scale,
                             cell_padding,
-                            style.clone(),
+                            cell_height(footer_height),
+                            Rc::clone(&shared_style),
                         )
                     })
-                    .chain(dummy_container(columns, min_width, min_column_width))),
-                style.clone(),
-            ))
-            .id(header)
-            .direction(scrollable::Direction::Both {
-                vertical: scrollable::Scrollbar::new()
-                    .width(0)
-                    .margin(0)
-                    .scroller_width(0),
-                horizontal: scrollable::Scrollbar::new()
-                    .width(0)
-                    .margin(0)
-                    .scroller_width(0),
-            });
+                    .chain(dummy_container(filler, debug)));
 
-            let body = scrollable(column(rows.iter().enumerate().map(|(row_index, _row)| {
-                style::wrapper::row(
-                    row(columns
-                        .iter()
-                        .enumerate()
-                        .filter(|(_, column)| column.is_visible())
-                        .map(|(col_index, column)| {
-                            body_container(
-                                col_index,
-                                row_index,
-                                column,
-                                _row,
-                                min_column_width,
-                                divider_width,
-                                cell_padding,
-                            )
-                        })
-                        .chain(dummy_container(columns, min_width, min_column_width))),
-                    style.clone(),
-                    row_index,
-                )
-                .into()
-            })))
-            .id(body)
-            .on_scroll(move |viewport| {
-                let offset = viewport.absolute_offset();
+                let footer_row = Slide::new(footer_row, shown.clone(), reduce_motion);
 
-                (on_sync)(scrollable::AbsoluteOffset { y: 0.0, ..offset })
-            })
-            .direction(scrollable::Direction::Both {
-                horizontal: scrollbar,
-                vertical: scrollbar,
-            })
-            .height(Length::Fill);
+                let footer = scrollable(style::wrapper::footer(footer_row, style.clone()))
+                    .id(footer)
+                    .height(footer_height)
+                    .direction(scrollable::Direction::Both {
+                        vertical: scrollable::Scrollbar::new()
+                            .width(0)
+                            .margin(0)
+                            .scroller_width(0),
+                        horizontal: scrollable::Scrollbar::new()
+                            .width(0)
+                            .margin(0)
+                            .scroller_width(0),
+                    });
 
-            let footer = footer.map(|footer| {
-                scrollable(style::wrapper::footer(
-                    row(columns
-                        .iter()
-                        .enumerate()
-                        .filter(|(_, column)| column.is_visible())
-                        .map(|(index, column)| {
-                            footer_container(
-                                index,
-                                column,
-                                columns,
-                                rows,
-                                on_column_drag,
-                                on_column_release.clone(),
-                                on_column_visibility.clone(),
-                                min_column_width,
-                                divider_width,
-                                cell_padding,
-                                style.clone(),
-                            )
-                        })
-                        .chain(dummy_container(columns, min_width, min_column_width))),
-                    style,
-                ))
-                .id(footer)
-                .direction(scrollable::Direction::Both {
-                    vertical: scrollable::Scrollbar::new()
-                        .width(0)
-                        .margin(0)
-                        .scroller_width(0),
-                    horizontal: scrollable::Scrollbar::new()
-                        .width(0)
-                        .margin(0)
-                        .scroller_width(0),
-                })
+                Some(footer)
             });
 
             let mut column = column![header, body];
@@ -352,7 +1726,63 @@ pub mod table {
                 column = column.push(footer);
             }
 
-            column.height(Length::Fill).into()
+            let content =
+                ScrollSync::new(column.height(height), sync_body.clone(), sync_targets, drag);
+
+            let content: Element<'a, Message, Theme, Renderer> = match max_height {
+                Some(max_height) => container(content).max_height(max_height).into(),
+                None => content.into(),
+            };
+
+            let content = if corner_radius > 0.0 {
+                // The renderer only clips to rectangles, so the content is inset until its
+                // corners fall within the rounded ones
+                let inset = corner_radius * (1.0 - std::f32::consts::FRAC_1_SQRT_2);
+
+                style::wrapper::frame(
+                    container(content).padding(inset.ceil()),
+                    style.clone(),
+                    corner_radius,
+                )
+            } else {
+                content
+            };
+
+            if find_bar {
+                let searched: Vec<usize> = columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, column)| is_shown(*index, column))
+                    .map(|(index, _)| index)
+                    .collect();
+
+                // Only run once the find bar has a query
+                let search = move |query: &str| -> Vec<(usize, Vec<usize>)> {
+                    rows.iter()
+                        .enumerate()
+                        .filter_map(|(row_index, row)| {
+                            let cells: Vec<usize> = searched
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, index)| {
+                                    columns[**index]
+                                        .search_text(row)
+                                        .is_some_and(|text| text.to_lowercase().contains(query))
+                                })
+                                .map(|(position, _)| position)
+                                .collect();
+
+                            (!cells.is_empty()).then_some((row_index, cells))
+                        })
+                        .collect()
+                };
+
+                Find::new(content, sync_body, search, style)
+                    .placeholder(localization.find_placeholder.clone())
+                    .into()
+            } else {
+                content
+            }
         }
     }
 
@@ -360,22 +1790,43 @@ pub mod table {
         index: usize,
         column: &'a Column,
         all_columns: &'a [Column],
+        widths: &[f32],
+        hidden: &[usize],
+        rows: &'a [Row],
         on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        on_release: Option<Rc<Message>>,
+        on_resize_start: Option<fn(usize) -> Message>,
         on_column_visibility: Option<fn(super::divider::ColumnVisibilityMessage) -> Message>,
         min_column_width: f32,
+        resize_step: Option<f32>,
+        show_width: bool,
+        read_only: bool,
+        debug: bool,
+        context_menu_font: Option<iced::Font>,
+        context_menu_text_size: iced::Pixels,
+        localization: &Localization,
+        drag: &Drag,
         divider_width: f32,
+        scale: f32,
         cell_padding: Padding,
-        style: <Theme as style::Catalog>::Style,
+        height: Length,
+        style: Rc<<Theme as style::Catalog>::Style>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
-        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer + 'a,
+        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
         Theme: style::Catalog + container::Catalog + 'a,
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let content = container(column.header(index))
+        let header = if let Some(summary) = column.header_summary(index, rows) {
+            column![column.header(index), summary].into()
+        } else {
+            column.header(index)
+        };
+
+        let content = container(header)
             .width(Length::Fill)
+            .height(height)
             .padding(cell_padding)
             .into();
 
@@ -383,12 +1834,24 @@ pub mod table {
             index,
             column,
             all_columns,
+            widths,
+            hidden,
             content,
             on_drag,
             on_release,
+            on_resize_start,
             on_column_visibility,
             min_column_width,
+            resize_step,
+            show_width,
+            read_only,
+            debug,
+            context_menu_font,
+            context_menu_text_size,
+            localization,
+            drag,
             divider_width,
+            scale,
             style,
         )
     }
@@ -398,26 +1861,41 @@ pub mod table {
         row_index: usize,
         column: &'a Column,
         row: &'a Row,
-        min_column_width: f32,
+        is_merged: bool,
+        width: f32,
         divider_width: f32,
         cell_padding: Padding,
+        style: &<Theme as style::Catalog>::Style,
     ) -> Element<'a, Message, Theme, Renderer>
     where
-        Renderer: iced::advanced::Renderer + 'a,
-        Theme: style::Catalog + container::Catalog + 'a,
+        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer + 'a,
+        Theme: style::Catalog + container::Catalog + iced::widget::text::Catalog + 'a,
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let width = column.width() + column.resize_offset().unwrap_or_default();
+        // Merged cells repeat the value above them, so they are left blank
+        let cell = if is_merged {
+            Element::from(Space::with_width(Length::Fill))
+        } else {
+            column.cell(col_index, row_index, row)
+        };
 
-        let content = container(column.cell(col_index, row_index, row))
-            .width(Length::Fill)
-            .padding(cell_padding);
+        let content = container(cell).width(Length::Fill).padding(cell_padding);
+
+        let content = match column.cell_tooltip(row).filter(|_| !is_merged) {
+            Some(tip) => tooltip(
+                content,
+                style::wrapper::tooltip(container(text(tip)).padding(4), style.clone()),
+                tooltip::Position::FollowCursor,
+            )
+            .into(),
+            None => Element::from(content),
+        };
 
         let spacing = Space::new(divider_width, Length::Shrink);
 
         row![content, spacing]
-            .width(width.max(min_column_width))
+            .width(width)
             .into()
     }
 
@@ -425,40 +1903,66 @@ pub mod table {
         index: usize,
         column: &'a Column,
         all_columns: &'a [Column],
-        rows: &'a [Row],
+        widths: &[f32],
+        hidden: &[usize],
+        footer: Option<Element<'a, Message, Theme, Renderer>>,
         on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        on_release: Option<Rc<Message>>,
+        on_resize_start: Option<fn(usize) -> Message>,
         on_column_visibility: Option<fn(super::divider::ColumnVisibilityMessage) -> Message>,
         min_column_width: f32,
+        resize_step: Option<f32>,
+        show_width: bool,
+        read_only: bool,
+        debug: bool,
+        context_menu_font: Option<iced::Font>,
+        context_menu_text_size: iced::Pixels,
+        localization: &Localization,
+        drag: &Drag,
         divider_width: f32,
+        scale: f32,
         cell_padding: Padding,
-        style: <Theme as style::Catalog>::Style,
+        height: Length,
+        style: Rc<<Theme as style::Catalog>::Style>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
-        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer + 'a,
+        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
         Theme: style::Catalog + container::Catalog + 'a,
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let content = if let Some(footer) = column.footer(index, rows) {
+        let content = if let Some(footer) = footer {
             container(footer)
                 .width(Length::Fill)
+                .height(height)
                 .padding(cell_padding)
                 .into()
         } else {
-            Element::from(Space::with_width(Length::Fill))
+            Element::from(Space::new(Length::Fill, height))
         };
 
         with_divider(
             index,
             column,
             all_columns,
+            widths,
+            hidden,
             content,
             on_drag,
             on_release,
+            on_resize_start,
             on_column_visibility,
             min_column_width,
+            resize_step,
+            show_width,
+            read_only,
+            debug,
+            context_menu_font,
+            context_menu_text_size,
+            localization,
+            drag,
             divider_width,
+            scale,
             style,
         )
     }
@@ -467,49 +1971,103 @@ pub mod table {
         index: usize,
         column: &'a Column,
         all_columns: &'a [Column],
+        widths: &[f32],
+        hidden: &[usize],
         content: Element<'a, Message, Theme, Renderer>,
         on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        on_release: Option<Rc<Message>>,
+        on_resize_start: Option<fn(usize) -> Message>,
         on_column_visibility: Option<fn(super::divider::ColumnVisibilityMessage) -> Message>,
         min_column_width: f32,
+        resize_step: Option<f32>,
+        show_width: bool,
+        read_only: bool,
+        debug: bool,
+        context_menu_font: Option<iced::Font>,
+        context_menu_text_size: iced::Pixels,
+        localization: &Localization,
+        drag: &Drag,
         divider_width: f32,
-        style: <Theme as style::Catalog>::Style,
+        scale: f32,
+        style: Rc<<Theme as style::Catalog>::Style>,
     ) -> Element<'a, Message, Theme, Renderer>
     where
-        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer + 'a,
+        Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
         Theme: style::Catalog + container::Catalog + 'a,
         Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let width =
-            (column.width() + column.resize_offset().unwrap_or_default()).max(min_column_width);
+        let width = widths[index];
+
+        let title = |column: &Column| {
+            let title = column.title();
+
+            if title.is_empty() {
+                localization.untitled_column.clone()
+            } else {
+                title
+            }
+        };
 
         if let Some((on_drag, on_release)) = on_drag.zip(on_release) {
             let old_width = column.width();
 
+            // Only needed to snap to the other shown columns, which requires a step
+            let other_widths: Vec<f32> = if resize_step.is_some() {
+                all_columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, col)| *i != index && col.is_visible() && !hidden.contains(i))
+                    .map(|(i, _)| widths[i] / scale)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
             let mut divider = Divider::new(
                 content,
                 divider_width,
-                column.id(),
-                column.title(),
-                move |offset| {
-                    let new_width = (old_width + offset).max(min_column_width);
+                column.id(index),
+                title(column),
+                move |offset, snap_to_columns| {
+                    let new_width = resized_width(
+                        old_width,
+                        offset,
+                        scale,
+                        resize_step,
+                        snap_to_columns,
+                        &other_widths,
+                        min_column_width,
+                    );
+
                     (on_drag)(index, new_width - old_width)
                 },
                 on_release,
                 style,
-            );
+            )
+            .resizable(column.resizable() && !read_only)
+            .on_drag_start(on_resize_start.map(|on_resize_start| (on_resize_start)(index)))
+            .show_width(show_width)
+            .debug(debug)
+            .menu_font(context_menu_font)
+            .menu_text_size(context_menu_text_size)
+            .localization(localization.clone())
+            .drag(drag.clone())
+            .variant(column.divider_style())
+            .key_step(resize_step.map(|step| step * scale));
 
             // Add column visibility if enabled
             if let Some(on_visibility) = on_column_visibility {
                 let other_columns: Vec<(String, String, bool)> = all_columns
                     .iter()
                     .enumerate()
-                    .filter(|(i, _)| *i != index)
-                    .map(|(_, col)| (col.id(), col.title(), col.is_visible()))
+                    .filter(|(i, col)| *i != index && col.hideable())
+                    .map(|(i, col)| (col.id(i), title(col), col.is_visible()))
                     .collect();
 
-                divider = divider.with_column_visibility(on_visibility, other_columns);
+                divider = divider
+                    .with_column_visibility(on_visibility, other_columns)
+                    .hideable(column.hideable());
             }
 
             container(divider)
@@ -522,27 +2080,268 @@ pub mod table {
         }
     }
 
-    // Used to enforce "min_width"
-    fn dummy_container<'a, Column, Row, Message, Theme, Renderer>(
-        columns: &'a [Column],
-        min_width: f32,
+    // The width a column is resized to once its divider is dragged by `offset`, rounded to
+    // the resize step or snapped to the closest of the widths of the other columns
+    fn resized_width(
+        old_width: f32,
+        offset: f32,
+        scale: f32,
+        resize_step: Option<f32>,
+        snap_to_columns: bool,
+        other_widths: &[f32],
         min_column_width: f32,
+    ) -> f32 {
+        let new_width = old_width + offset / scale;
+
+        match resize_step {
+            Some(_) if snap_to_columns => other_widths
+                .iter()
+                .copied()
+                .min_by(|a, b| (a - new_width).abs().total_cmp(&(b - new_width).abs()))
+                .unwrap_or(new_width),
+            Some(step) => (new_width / step).round() * step,
+            None => new_width,
+        }
+        .max(min_column_width)
+    }
+
+    // Keeps the content scrollable without taking any space
+    fn hidden_scrollbar() -> scrollable::Scrollbar {
+        scrollable::Scrollbar::new()
+            .width(0)
+            .margin(0)
+            .scroller_width(0)
+    }
+
+    // Only a fixed height can be filled by the cells, since the header & footer
+    // scrollables lay out their content with an unbounded height
+    fn cell_height(height: Length) -> Length {
+        match height {
+            Length::Fixed(_) => height,
+            _ => Length::Shrink,
+        }
+    }
+
+    // Used to enforce "min_width"
+    fn dummy_container<'a, Message, Theme, Renderer>(
+        width: Option<f32>,
+        debug: bool,
     ) -> Option<Element<'a, Message, Theme, Renderer>>
     where
         Renderer: iced::advanced::Renderer + 'a,
         Theme: style::Catalog + container::Catalog + 'a,
-        Column: self::Column<'a, Message, Theme, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let total_width: f32 = columns
-            .iter()
-            .map(|column| {
-                (column.width() + column.resize_offset().unwrap_or_default()).max(min_column_width)
-            })
-            .sum();
+        width.map(|width| {
+            let filler = container(Space::with_width(width));
+
+            if debug {
+                Outline::new(filler, debug::FILLER).into()
+            } else {
+                filler.into()
+            }
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use iced::{Renderer, Theme};
+
+        struct TestColumn {
+            width: f32,
+            resize_offset: Option<f32>,
+            is_visible: bool,
+        }
+
+        impl TestColumn {
+            fn new(width: f32) -> Self {
+                Self {
+                    width,
+                    resize_offset: None,
+                    is_visible: true,
+                }
+            }
+        }
+
+        impl<'a> Column<'a, (), Theme, Renderer> for TestColumn {
+            type Row = ();
+
+            fn header(&'a self, _col_index: usize) -> Element<'a, (), Theme, Renderer> {
+                text("").into()
+            }
+
+            fn cell(
+                &'a self,
+                _col_index: usize,
+                _row_index: usize,
+                _row: &'a Self::Row,
+            ) -> Element<'a, (), Theme, Renderer> {
+                text("").into()
+            }
+
+            fn width(&self) -> f32 {
+                self.width
+            }
+
+            fn resize_offset(&self) -> Option<f32> {
+                self.resize_offset
+            }
+
+            fn is_visible(&self) -> bool {
+                self.is_visible
+            }
+        }
+
+        type TestTable<'a> = Table<'a, TestColumn, (), (), Theme, Renderer>;
+
+        fn bounds<'a>(
+            columns: &'a [TestColumn],
+            configure: impl FnOnce(TestTable<'a>) -> TestTable<'a>,
+        ) -> Vec<ColumnBounds> {
+            let table = table(
+                scrollable::Id::unique(),
+                scrollable::Id::unique(),
+                columns,
+                &[],
+                |_| (),
+            );
+
+            configure(table).column_bounds(1000.0)
+        }
+
+        #[test]
+        fn filler_reaches_min_width() {
+            let columns = [TestColumn::new(100.0), TestColumn::new(50.0)];
+
+            let bounds = bounds(&columns, |table| table.min_width(400.0));
+
+            assert_eq!(bounds.len(), 3);
+            assert_eq!(bounds[2].index, None);
+            assert_eq!(bounds[2].x, 150.0);
+            assert_eq!(bounds.iter().map(|column| column.width).sum::<f32>(), 400.0);
+        }
 
-        let remaining = min_width - total_width;
+        #[test]
+        fn no_filler_beyond_min_width() {
+            let columns = [TestColumn::new(300.0), TestColumn::new(200.0)];
 
-        (remaining > 0.0).then(|| container(Space::with_width(remaining)).into())
+            let bounds = bounds(&columns, |table| table.min_width(400.0));
+
+            assert!(bounds.iter().all(|column| column.index.is_some()));
+        }
+
+        #[test]
+        fn hidden_columns_are_excluded() {
+            let columns = [
+                TestColumn::new(100.0),
+                TestColumn {
+                    is_visible: false,
+                    ..TestColumn::new(50.0)
+                },
+                TestColumn::new(80.0),
+            ];
+
+            let bounds = bounds(&columns, |table| table);
+
+            assert_eq!(
+                bounds,
+                vec![
+                    ColumnBounds {
+                        index: Some(0),
+                        x: 0.0,
+                        width: 100.0,
+                    },
+                    ColumnBounds {
+                        index: Some(2),
+                        x: 100.0,
+                        width: 80.0,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn widths_are_clamped() {
+            let columns = [
+                TestColumn::new(1.0),
+                TestColumn {
+                    resize_offset: Some(-200.0),
+                    ..TestColumn::new(100.0)
+                },
+                TestColumn {
+                    resize_offset: Some(20.0),
+                    ..TestColumn::new(100.0)
+                },
+            ];
+
+            let bounds = bounds(&columns, |table| table.min_column_width(10.0).scale(2.0));
+
+            let widths: Vec<f32> = bounds.iter().map(|column| column.width).collect();
+
+            assert_eq!(widths, vec![20.0, 20.0, 240.0]);
+            assert_eq!(bounds[2].x, 40.0);
+        }
+
+        #[test]
+        fn scale_is_clamped() {
+            let columns = [TestColumn::new(100.0)];
+
+            let widths = |scale: f32| -> Vec<f32> {
+                bounds(&columns, |table| table.scale(scale))
+                    .iter()
+                    .map(|column| column.width)
+                    .collect()
+            };
+
+            assert_eq!(widths(10.0), vec![400.0]);
+            assert_eq!(widths(0.0), vec![25.0]);
+        }
+
+        #[test]
+        fn overflow_menu_makes_room_for_its_button() {
+            let columns = [
+                TestColumn::new(600.0),
+                TestColumn::new(390.0),
+                TestColumn::new(100.0),
+            ];
+
+            let indices = |bounds: Vec<ColumnBounds>| -> Vec<Option<usize>> {
+                bounds.iter().map(|column| column.index).collect()
+            };
+
+            let without = bounds(&columns, |table| table.responsive_columns(true));
+            let with = bounds(&columns, |table| {
+                table.responsive_columns(true).overflow_menu(true)
+            });
+
+            assert_eq!(indices(without), vec![Some(0), Some(1)]);
+            assert_eq!(indices(with), vec![Some(0)]);
+        }
+
+        #[test]
+        fn resized_widths_follow_the_step() {
+            let resize = |offset: f32, snap_to_columns: bool| {
+                let other_widths = [60.0, 180.0];
+
+                resized_width(
+                    100.0,
+                    offset,
+                    2.0,
+                    Some(10.0),
+                    snap_to_columns,
+                    &other_widths,
+                    4.0,
+                )
+            };
+
+            assert_eq!(resize(26.0, false), 110.0);
+            assert_eq!(resize(-400.0, false), 4.0);
+            assert_eq!(resize(100.0, true), 180.0);
+            assert_eq!(
+                resized_width(100.0, 100.0, 2.0, None, true, &[60.0], 4.0),
+                150.0
+            );
+        }
     }
 }
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::text::{self, Paragraph, Text};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::{alignment, event, mouse, Element, Length, Point, Rectangle, Size};
+
+use crate::style;
+
+#[derive(Debug, Default)]
+struct State {
+    is_hovered: bool,
+    is_pressed: bool,
+}
+
+/// Creates a new [`Link`] displaying `content` as a hyperlink publishing `on_press` when
+/// clicked.
+///
+/// Unlike a button, a link has no padding or background, so it fits tight cells.
+pub fn link_cell<Message, Theme>(
+    content: impl Into<String>,
+    on_press: Message,
+) -> Link<Message, Theme>
+where
+    Theme: style::Catalog,
+{
+    Link {
+        content: content.into(),
+        on_press,
+        style: Default::default(),
+    }
+}
+
+/// A text styled as a hyperlink, see [`link_cell`].
+///
+/// It's underlined & shows a hand cursor while hovered.
+pub struct Link<Message, Theme>
+where
+    Theme: style::Catalog,
+{
+    content: String,
+    on_press: Message,
+    style: <Theme as style::Catalog>::Style,
+}
+
+impl<Message, Theme> fmt::Debug for Link<Message, Theme>
+where
+    Theme: style::Catalog,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link")
+            .field("content", &self.content)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Message, Theme> Link<Message, Theme>
+where
+    Theme: style::Catalog,
+{
+    /// Sets the style of the [`Link`].
+    pub fn style(self, style: impl Into<<Theme as style::Catalog>::Style>) -> Self {
+        Self {
+            style: style.into(),
+            ..self
+        }
+    }
+
+    fn text<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Text<&str, Renderer::Font>
+    where
+        Renderer: text::Renderer,
+    {
+        Text {
+            content: &self.content,
+            bounds,
+            size: renderer.default_size(),
+            line_height: text::LineHeight::default(),
+            font: renderer.default_font(),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            wrapping: text::Wrapping::None,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Link<Message, Theme>
+where
+    Message: Clone,
+    Theme: style::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // Long links are cut at the width of the cell rather than overflowing it
+        let paragraph = Renderer::Paragraph::with_text(self.text(renderer, limits.max()));
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, paragraph.min_bounds()))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            event::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let is_hovered = cursor.is_over(layout.bounds());
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(layout.bounds()) =>
+            {
+                state.is_pressed = true;
+                shell.capture_event();
+            }
+            // Like a button, a press dragged away from the link is cancelled
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor.is_over(layout.bounds()) {
+                    shell.publish(self.on_press.clone());
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let color = theme.link(&self.style);
+
+        let text = self.text(renderer, bounds.size());
+
+        renderer.fill_text(
+            Text {
+                content: self.content.clone(),
+                bounds: bounds.size(),
+                size: text.size,
+                line_height: text.line_height,
+                font: text.font,
+                align_x: text.align_x,
+                align_y: text.align_y,
+                wrapping: text.wrapping,
+                shaping: text.shaping,
+            },
+            Point::new(bounds.x, bounds.y),
+            color,
+            bounds.intersection(viewport).unwrap_or_default(),
+        );
+
+        if tree.state.downcast_ref::<State>().is_hovered {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 1.0,
+                        height: 1.0,
+                        ..bounds
+                    },
+                    ..Default::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Link<Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: style::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(link: Link<Message, Theme>) -> Self {
+        Element::new(link)
+    }
+}
@@ -0,0 +1,140 @@
+//! Translate the built-in text of a [`Table`](crate::Table).
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The text displayed by the [`Table`](crate::Table) & the widgets of this crate.
+///
+/// Set it with [`Table::localization`](crate::Table::localization) so non-English apps
+/// don't have to patch the widget. The functions can capture runtime data, e.g. a locale
+/// loaded from a file.
+#[derive(Clone)]
+pub struct Localization {
+    /// The context menu entry hiding a column, given its title.
+    ///
+    /// Defaults to "Hide {title}".
+    pub hide_column: Arc<dyn Fn(&str) -> String + Send + Sync>,
+    /// The context menu entry opening the list of the other columns to show or hide.
+    ///
+    /// Defaults to "Columns".
+    pub columns: String,
+    /// The label shown next to a divider while its column is resized, given its width.
+    ///
+    /// Defaults to "{width} px".
+    pub resize_width: Arc<dyn Fn(f32) -> String + Send + Sync>,
+    /// The placeholder of the find bar while its query is empty.
+    ///
+    /// Defaults to "Find".
+    pub find_placeholder: String,
+    /// The title of a column that doesn't override
+    /// [`Column::title`](crate::table::Column::title).
+    ///
+    /// Defaults to "Column".
+    pub untitled_column: String,
+    /// The summary of the rows of a table, given the number of displayed, selected & total
+    /// rows, see [`status_text`](Self::status_text).
+    ///
+    /// Defaults to e.g. "1,204 rows • 3 selected • filtered from 8,911".
+    pub status: Arc<dyn Fn(usize, usize, usize) -> String + Send + Sync>,
+    /// The time elapsed since a timestamp, or until it when it's in the future, as
+    /// displayed by a [`DateTimeColumn`](crate::presets::DateTimeColumn).
+    ///
+    /// Defaults to e.g. "just now", "3 min ago" or "in 2 h".
+    pub relative_time: Arc<dyn Fn(Duration, bool) -> String + Send + Sync>,
+    /// The link expanding a [`ShowMore`](crate::ShowMore).
+    ///
+    /// Defaults to "more".
+    pub show_more: String,
+    /// The link collapsing a [`ShowMore`](crate::ShowMore).
+    ///
+    /// Defaults to "less".
+    pub show_less: String,
+}
+
+impl Localization {
+    /// Summarizes the rows of a table for its
+    /// [`status_bar`](crate::Table::status_bar) with [`status`](Self::status).
+    pub fn status_text(&self, rows: usize, selected: usize, total: usize) -> String {
+        (self.status)(rows, selected, total)
+    }
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self {
+            hide_column: Arc::new(|title| format!("Hide {title}")),
+            columns: String::from("Columns"),
+            resize_width: Arc::new(|width| format!("{} px", width.round())),
+            find_placeholder: String::from("Find"),
+            untitled_column: String::from("Column"),
+            status: Arc::new(status),
+            relative_time: Arc::new(relative_time),
+            show_more: String::from("more"),
+            show_less: String::from("less"),
+        }
+    }
+}
+
+impl fmt::Debug for Localization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localization")
+            .field("columns", &self.columns)
+            .field("find_placeholder", &self.find_placeholder)
+            .field("untitled_column", &self.untitled_column)
+            .field("show_more", &self.show_more)
+            .field("show_less", &self.show_less)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Inserts `separator` between each group of three digits of `digits`, e.g. "1,204".
+pub(crate) fn group_thousands(digits: &str, separator: char) -> String {
+    let mut text = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            text.push(separator);
+        }
+
+        text.push(digit);
+    }
+
+    text
+}
+
+fn status(rows: usize, selected: usize, total: usize) -> String {
+    let thousands = |value: usize| group_thousands(&value.to_string(), ',');
+
+    let mut text = format!(
+        "{} {}",
+        thousands(rows),
+        if rows == 1 { "row" } else { "rows" }
+    );
+
+    if selected > 0 {
+        text.push_str(&format!(" • {} selected", thousands(selected)));
+    }
+
+    if rows != total {
+        text.push_str(&format!(" • filtered from {}", thousands(total)));
+    }
+
+    text
+}
+
+fn relative_time(elapsed: Duration, is_future: bool) -> String {
+    let seconds = elapsed.as_secs();
+
+    let amount = match seconds {
+        0..=59 => return String::from("just now"),
+        60..=3599 => format!("{} min", seconds / 60),
+        3600..=86_399 => format!("{} h", seconds / 3600),
+        _ => format!("{} d", seconds / 86_400),
+    };
+
+    if is_future {
+        format!("in {amount}")
+    } else {
+        format!("{amount} ago")
+    }
+}
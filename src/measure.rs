@@ -0,0 +1,55 @@
+use iced::advanced::text::{self, Paragraph, Text};
+use iced::{alignment, Pixels, Size};
+
+/// Measures the width needed to display the widest of the provided strings.
+///
+/// This is useful to compute initial [`Column::width`](crate::table::Column::width)s from
+/// data at startup. The returned width only accounts for the text itself, so cell padding
+/// and the divider width should be added on top of it.
+pub fn measure_text_column<Renderer>(
+    _renderer: &Renderer,
+    texts: impl IntoIterator<Item = impl AsRef<str>>,
+    font: Renderer::Font,
+    size: impl Into<Pixels>,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    let size = size.into();
+
+    texts
+        .into_iter()
+        .map(|content| {
+            Renderer::Paragraph::with_text(Text {
+                content: content.as_ref(),
+                bounds: Size::INFINITY,
+                size,
+                line_height: text::LineHeight::default(),
+                font,
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                wrapping: text::Wrapping::None,
+                shaping: text::Shaping::Advanced,
+            })
+            .min_width()
+        })
+        .fold(0.0, f32::max)
+}
+
+/// Measures the width of `chars` characters, using the width of "0" like the CSS `ch`
+/// unit.
+///
+/// This is useful to size columns from a data model, e.g. 12 characters for a
+/// `VARCHAR(12)` column, without guessing pixels. Like [`measure_text_column`], cell
+/// padding and the divider width should be added on top of it.
+pub fn measure_ch_width<Renderer>(
+    renderer: &Renderer,
+    chars: usize,
+    font: Renderer::Font,
+    size: impl Into<Pixels>,
+) -> f32
+where
+    Renderer: text::Renderer,
+{
+    chars as f32 * measure_text_column(renderer, ["0"], font, size)
+}
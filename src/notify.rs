@@ -0,0 +1,114 @@
+use iced::advanced::layout::Layout;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::{event, Element, Rectangle};
+
+use crate::forward::forward;
+
+#[derive(Debug, Default)]
+struct State {
+    key: Option<Vec<String>>,
+}
+
+/// Wraps some content and publishes a message whenever its key changes.
+///
+/// The key is assumed to start out empty, so nothing is published until it
+/// first becomes non-empty.
+pub(crate) struct Notify<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    key: Vec<String>,
+    on_change: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Notify<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        key: Vec<String>,
+        on_change: Option<Message>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            key,
+            on_change,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Notify<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let previous = state.key.as_deref().unwrap_or_default();
+
+        if previous != self.key.as_slice() {
+            if let Some(on_change) = self.on_change.clone() {
+                shell.publish(on_change);
+            }
+
+            state.key = Some(self.key.clone());
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    forward!(
+        children,
+        diff,
+        size,
+        layout,
+        mouse_interaction,
+        draw,
+        overlay,
+        operate
+    );
+}
+
+impl<'a, Message, Theme, Renderer> From<Notify<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(notify: Notify<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(notify)
+    }
+}
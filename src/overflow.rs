@@ -0,0 +1,345 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Overlay, Shell};
+use iced::mouse::Cursor;
+use iced::{
+    event, keyboard, mouse, overlay, touch, Color, Element, Point, Rectangle, Size, Vector,
+};
+
+use crate::body::FocusedRow;
+use crate::forward::forward;
+use crate::style;
+
+/// The width of the button trailing the header, before scaling.
+pub(crate) const WIDTH: f32 = 28.0;
+
+#[derive(Debug, Default)]
+struct State {
+    is_open: bool,
+    is_hovered: bool,
+}
+
+/// Trails the header while columns are hidden for lack of space, and opens a menu of the
+/// hidden columns along with their values for the focused row once clicked.
+///
+/// The menu is built whenever it is shown, for the row focused at the time.
+pub(crate) struct Overflow<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    menu: Box<dyn Fn(Option<usize>) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    // The menu of the last overlay, kept until the next one
+    panel: Option<Element<'a, Message, Theme, Renderer>>,
+    focused_row: FocusedRow,
+    style: <Theme as style::Catalog>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Overflow<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        menu: impl Fn(Option<usize>) -> Element<'a, Message, Theme, Renderer> + 'a,
+        focused_row: FocusedRow,
+        style: <Theme as style::Catalog>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            menu: Box::new(menu),
+            panel: None,
+            focused_row,
+            style,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Overflow<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content), widget::Tree::empty()]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        // The menu is diffed once built
+        tree.children[0].diff(&self.content);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | event::Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                state.is_open = true;
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+                return;
+            }
+            event::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let is_hovered = cursor.is_over(layout.bounds());
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_open || state.is_hovered {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..Default::default()
+                },
+                theme
+                    .divider(&self.style, true)
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let widget::Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return self.content.as_widget_mut().overlay(
+                &mut children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                viewport,
+                translation,
+            );
+        }
+
+        let panel = self.panel.insert((self.menu)(self.focused_row.get()));
+        children[1].diff(&*panel);
+
+        Some(overlay::Element::new(Box::new(Menu {
+            content: panel,
+            tree: &mut children[1],
+            state,
+            button: layout.bounds() + translation,
+        })))
+    }
+
+    forward!(size, layout, operate);
+}
+
+impl<'a, Message, Theme, Renderer> From<Overflow<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(overflow: Overflow<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(overflow)
+    }
+}
+
+// Opens below the button, aligned with its right edge
+struct Menu<'a, 'b, Message, Theme, Renderer> {
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut widget::Tree,
+    state: &'b mut State,
+    button: Rectangle,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let top = self.button.y + self.button.height;
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(bounds.width, (bounds.height - top).max(0.0)),
+        );
+
+        let node = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+
+        let right = self.button.x + self.button.width;
+        let x = (right - node.size().width).clamp(0.0, (bounds.width - node.size().width).max(0.0));
+
+        node.move_to(Point::new(x, top))
+    }
+
+    fn update(
+        &mut self,
+        event: &iced::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(_))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(layout.bounds()) =>
+            {
+                self.state.is_open = false;
+                shell.invalidate_layout();
+                shell.request_redraw();
+
+                // Clicking the button again closes the menu without opening it back, while
+                // any other click goes through
+                if cursor.is_over(self.button) {
+                    shell.capture_event();
+                }
+
+                return;
+            }
+            iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.state.is_open = false;
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+                return;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&*self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        self.content.as_widget().draw(
+            &*self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+}
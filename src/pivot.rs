@@ -0,0 +1,197 @@
+//! Transpose records so each row displays an attribute and each column a record.
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use iced::widget::{container, scrollable};
+use iced::Element;
+
+use crate::style;
+use crate::table::{self, Table};
+
+/// Records transposed into attribute rows and record columns.
+///
+/// The attribute definitions are regular [`Column`](table::Column)s: the header of an
+/// attribute labels its row and its cells are rendered once per record. The column header
+/// of each record is rendered by the [`key`](Pivot::key) attribute.
+#[derive(Debug)]
+pub struct Pivot<C, R> {
+    attributes: Rc<[C]>,
+    columns: Vec<PivotColumn<C, R>>,
+}
+
+/// A column of a [`Pivot`], either the attribute labels or a single record.
+#[derive(Debug)]
+pub struct PivotColumn<C, R> {
+    kind: Kind<R>,
+    attributes: Rc<[C]>,
+    key: usize,
+    width: f32,
+    _attribute: PhantomData<fn() -> C>,
+}
+
+#[derive(Debug)]
+enum Kind<R> {
+    Label,
+    Record { index: usize, record: R },
+}
+
+impl<C, R> Pivot<C, R> {
+    /// Creates a new [`Pivot`] of the provided attribute definitions & records.
+    pub fn new(
+        attributes: impl IntoIterator<Item = C>,
+        records: impl IntoIterator<Item = R>,
+    ) -> Self {
+        let attributes: Rc<[C]> = attributes.into_iter().collect();
+
+        // Without attributes there is nothing to label the records with
+        let columns = if attributes.is_empty() {
+            vec![]
+        } else {
+            std::iter::once(Kind::Label)
+                .chain(
+                    records
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, record)| Kind::Record { index, record }),
+                )
+                .map(|kind| PivotColumn {
+                    width: match kind {
+                        Kind::Label => 120.0,
+                        Kind::Record { .. } => 160.0,
+                    },
+                    kind,
+                    attributes: attributes.clone(),
+                    key: 0,
+                    _attribute: PhantomData,
+                })
+                .collect()
+        };
+
+        Self {
+            attributes,
+            columns,
+        }
+    }
+
+    /// Sets the index of the attribute used to render the header of each record.
+    pub fn key(mut self, key: usize) -> Self {
+        let key = key.min(self.attributes.len().saturating_sub(1));
+
+        self.columns.iter_mut().for_each(|column| column.key = key);
+        self
+    }
+
+    /// Sets the width of the column labeling each attribute.
+    pub fn label_width(mut self, width: f32) -> Self {
+        self.columns
+            .iter_mut()
+            .filter(|column| matches!(column.kind, Kind::Label))
+            .for_each(|column| column.width = width);
+        self
+    }
+
+    /// Sets the width of each record column.
+    pub fn record_width(mut self, width: f32) -> Self {
+        self.columns
+            .iter_mut()
+            .filter(|column| matches!(column.kind, Kind::Record { .. }))
+            .for_each(|column| column.width = width);
+        self
+    }
+
+    /// Returns the attribute definitions, displayed as rows.
+    pub fn attributes(&self) -> &[C] {
+        &self.attributes
+    }
+
+    /// Returns the columns of the transposed table.
+    pub fn columns(&self) -> &[PivotColumn<C, R>] {
+        &self.columns
+    }
+
+    /// Returns an iterator over the records.
+    pub fn records(&self) -> impl Iterator<Item = &R> {
+        self.columns.iter().filter_map(|column| match &column.kind {
+            Kind::Label => None,
+            Kind::Record { record, .. } => Some(record),
+        })
+    }
+
+    /// Returns the record at `index` mutably.
+    pub fn record_mut(&mut self, index: usize) -> Option<&mut R> {
+        self.columns
+            .iter_mut()
+            .filter_map(|column| match &mut column.kind {
+                Kind::Label => None,
+                Kind::Record { record, .. } => Some(record),
+            })
+            .nth(index)
+    }
+}
+
+/// Creates a new [`Table`] displaying the provided [`Pivot`].
+///
+/// See [`table`](crate::table()) for the meaning of the other arguments.
+pub fn pivot_table<'a, C, R, Message, Theme, Renderer>(
+    header: scrollable::Id,
+    body: scrollable::Id,
+    pivot: &'a Pivot<C, R>,
+    on_sync: fn(scrollable::AbsoluteOffset) -> Message,
+) -> Table<'a, PivotColumn<C, R>, C, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + container::Catalog,
+{
+    table::table(header, body, pivot.columns(), pivot.attributes(), on_sync)
+}
+
+impl<'a, C, R, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for PivotColumn<C, R>
+where
+    C: table::Column<'a, Message, Theme, Renderer, Row = R>,
+{
+    type Row = C;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        let key = &self.attributes[self.key];
+
+        match &self.kind {
+            Kind::Label => key.header(self.key),
+            Kind::Record { index, record } => key.cell(self.key, *index, record),
+        }
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        match &self.kind {
+            Kind::Label => row.header(row_index),
+            Kind::Record { index, record } => row.cell(row_index, *index, record),
+        }
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn resize_offset(&self) -> Option<f32> {
+        None
+    }
+
+    fn id(&self, _col_index: usize) -> String {
+        match &self.kind {
+            Kind::Label => String::from("pivot_label"),
+            Kind::Record { index, .. } => format!("pivot_record_{index}"),
+        }
+    }
+
+    fn hideable(&self) -> bool {
+        false
+    }
+
+    fn resizable(&self) -> bool {
+        false
+    }
+}
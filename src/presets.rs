@@ -0,0 +1,673 @@
+//! Ready-made columns for common kinds of data.
+//!
+//! Each column is identified by its title followed by its index by default, which changes
+//! when columns are reordered. Give it a stable [`id`](table::Column::id) with its `id`
+//! builder when the layout of the columns is persisted.
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iced::advanced::text;
+use iced::widget;
+use iced::{alignment, Element, Length};
+
+use crate::localization::{self, Localization};
+use crate::table;
+
+/// The id of a preset column, falling back to its title followed by its index unless set.
+#[derive(Debug, Clone, Default)]
+struct Id(Option<String>);
+
+impl Id {
+    fn resolve(&self, title: &str, col_index: usize) -> String {
+        self.0
+            .clone()
+            .unwrap_or_else(|| format!("{title}_{col_index}"))
+    }
+}
+
+/// Creates a new [`ComputedColumn`] titled `title` displaying the value derived from each
+/// row by `value`, e.g. the price times the quantity of an order line.
+pub fn computed_column<R, T>(title: impl Into<String>, value: fn(&R) -> T) -> ComputedColumn<R, T>
+where
+    T: Display,
+{
+    ComputedColumn {
+        title: title.into(),
+        id: Id::default(),
+        value,
+        width: 120.0,
+    }
+}
+
+/// A column displaying a value derived from its rows, without a dedicated
+/// [`Column`](table::Column) implementation.
+///
+/// The derived value is also searched by the find bar & merged by
+/// [`Table::merge_duplicates`](crate::Table::merge_duplicates). Use [`value`](Self::value) to
+/// sort, filter or export rows by the same value. Wrap it in [`Columns`](crate::Columns) to
+/// resize or hide it.
+#[derive(Debug, Clone)]
+pub struct ComputedColumn<R, T> {
+    title: String,
+    id: Id,
+    value: fn(&R) -> T,
+    width: f32,
+}
+
+impl<R, T> ComputedColumn<R, T>
+where
+    T: Display,
+{
+    /// Sets the initial width of the [`ComputedColumn`], 120 by default.
+    pub fn width(self, width: f32) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Sets the [`id`](table::Column::id) of the [`ComputedColumn`].
+    pub fn id(self, id: impl Into<String>) -> Self {
+        Self {
+            id: Id(Some(id.into())),
+            ..self
+        }
+    }
+
+    /// Returns the value of the [`ComputedColumn`] for the given row.
+    pub fn value(&self, row: &R) -> T {
+        (self.value)(row)
+    }
+}
+
+impl<'a, R, T, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for ComputedColumn<R, T>
+where
+    T: Display,
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = R;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        widget::text(&self.title).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        widget::text(self.value(row).to_string()).into()
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn resize_offset(&self) -> Option<f32> {
+        None
+    }
+
+    fn id(&self, col_index: usize) -> String {
+        self.id.resolve(&self.title, col_index)
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn search_text(&self, row: &Self::Row) -> Option<String> {
+        Some(self.value(row).to_string())
+    }
+
+    fn merge_key(&self, row: &Self::Row) -> Option<String> {
+        Some(self.value(row).to_string())
+    }
+}
+
+/// Creates a new [`NumberColumn`] titled `title` displaying the amount of money of each row,
+/// prefixed with the currency `symbol` & rounded to cents.
+pub fn currency_column<R>(
+    title: impl Into<String>,
+    symbol: impl Into<String>,
+    value: fn(&R) -> f64,
+) -> NumberColumn<R> {
+    NumberColumn {
+        prefix: symbol.into(),
+        decimals: Some(2),
+        ..NumberColumn::new(title.into(), value)
+    }
+}
+
+/// Creates a new [`NumberColumn`] titled `title` displaying the quantity of each row,
+/// followed by its `unit`, e.g. "kg".
+///
+/// Quantities are displayed with their natural precision, e.g. "1.25 kg" & "3 kg", unless
+/// [`decimals`](NumberColumn::decimals) are set.
+pub fn unit_column<R>(
+    title: impl Into<String>,
+    unit: impl Into<String>,
+    value: fn(&R) -> f64,
+) -> NumberColumn<R> {
+    NumberColumn {
+        suffix: format!(" {}", unit.into()),
+        ..NumberColumn::new(title.into(), value)
+    }
+}
+
+/// A right-aligned column of numbers summed in its footer.
+///
+/// Numbers are grouped by thousands, e.g. "$1,204.50". The separators follow the English
+/// convention by default and can be changed with [`separators`](Self::separators).
+#[derive(Debug, Clone)]
+pub struct NumberColumn<R> {
+    title: String,
+    id: Id,
+    value: fn(&R) -> f64,
+    width: f32,
+    prefix: String,
+    suffix: String,
+    decimals: Option<usize>,
+    thousands_separator: char,
+    decimal_separator: char,
+    sum: bool,
+}
+
+impl<R> NumberColumn<R> {
+    fn new(title: String, value: fn(&R) -> f64) -> Self {
+        Self {
+            title,
+            id: Id::default(),
+            value,
+            width: 120.0,
+            prefix: String::new(),
+            suffix: String::new(),
+            decimals: None,
+            thousands_separator: ',',
+            decimal_separator: '.',
+            sum: true,
+        }
+    }
+
+    /// Sets the initial width of the [`NumberColumn`], 120 by default.
+    pub fn width(self, width: f32) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Sets the [`id`](table::Column::id) of the [`NumberColumn`].
+    pub fn id(self, id: impl Into<String>) -> Self {
+        Self {
+            id: Id(Some(id.into())),
+            ..self
+        }
+    }
+
+    /// Sets the number of decimals displayed by the [`NumberColumn`], rounding the values.
+    pub fn decimals(self, decimals: usize) -> Self {
+        Self {
+            decimals: Some(decimals),
+            ..self
+        }
+    }
+
+    /// Sets the separators of thousands & decimals, e.g. `('.', ',')` for "1.204,50".
+    pub fn separators(self, thousands: char, decimal: char) -> Self {
+        Self {
+            thousands_separator: thousands,
+            decimal_separator: decimal,
+            ..self
+        }
+    }
+
+    /// Sets whether the footer of the [`NumberColumn`] shows the sum of its rows.
+    pub fn sum(self, sum: bool) -> Self {
+        Self { sum, ..self }
+    }
+
+    /// Returns the value of the [`NumberColumn`] for the given row.
+    pub fn value(&self, row: &R) -> f64 {
+        (self.value)(row)
+    }
+
+    /// Formats a value the way the [`NumberColumn`] displays it.
+    ///
+    /// Values that aren't finite are displayed as is, e.g. "NaN".
+    pub fn format(&self, value: f64) -> String {
+        self.format_with(value, self.decimals)
+    }
+
+    /// Formats the sum of the rows the way the footer of the [`NumberColumn`] displays it.
+    ///
+    /// Without [`decimals`](Self::decimals), the sum is rounded to the most precise of its
+    /// values, so e.g. 1.1 + 2.2 is displayed as "3.3" rather than "3.3000000000000003".
+    pub fn format_sum(&self, rows: &[R]) -> String {
+        let sum = rows.iter().map(|row| self.value(row)).sum();
+
+        let decimals = self.decimals.or_else(|| {
+            Some(
+                rows.iter()
+                    .map(|row| precision(self.value(row)))
+                    .max()
+                    .unwrap_or_default(),
+            )
+        });
+
+        self.format_with(sum, decimals)
+    }
+
+    fn format_with(&self, value: f64, decimals: Option<usize>) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let fixed = match decimals {
+            Some(decimals) => format!("{:.*}", decimals, value.abs()),
+            None => value.abs().to_string(),
+        };
+        let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+
+        // Values rounding to zero are displayed without a sign
+        let is_negative = value < 0.0 && fixed.bytes().any(|digit| (b'1'..=b'9').contains(&digit));
+
+        let mut text = String::new();
+
+        if is_negative {
+            text.push('-');
+        }
+
+        text.push_str(&self.prefix);
+        text.push_str(&localization::group_thousands(
+            integer,
+            self.thousands_separator,
+        ));
+
+        if !fraction.is_empty() {
+            text.push(self.decimal_separator);
+            text.push_str(fraction);
+        }
+
+        text.push_str(&self.suffix);
+        text
+    }
+}
+
+impl<'a, R, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for NumberColumn<R>
+where
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = R;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        widget::text(&self.title)
+            .width(Length::Fill)
+            .align_x(alignment::Horizontal::Right)
+            .into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        widget::text(self.format(self.value(row)))
+            .width(Length::Fill)
+            .align_x(alignment::Horizontal::Right)
+            .into()
+    }
+
+    fn footer(
+        &'a self,
+        _col_index: usize,
+        rows: &'a [Self::Row],
+    ) -> Option<Element<'a, Message, Theme, Renderer>> {
+        if !self.sum {
+            return None;
+        }
+
+        Some(
+            widget::text(self.format_sum(rows))
+                .width(Length::Fill)
+                .align_x(alignment::Horizontal::Right)
+                .into(),
+        )
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn resize_offset(&self) -> Option<f32> {
+        None
+    }
+
+    fn id(&self, col_index: usize) -> String {
+        self.id.resolve(&self.title, col_index)
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn search_text(&self, row: &Self::Row) -> Option<String> {
+        Some(self.format(self.value(row)))
+    }
+}
+
+// The number of decimals of the shortest representation of a value, e.g. 2 for 1.25
+fn precision(value: f64) -> usize {
+    value
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// Creates a new [`DateTimeColumn`] titled `title` displaying the timestamp of each row.
+pub fn datetime_column<R>(
+    title: impl Into<String>,
+    value: fn(&R) -> SystemTime,
+) -> DateTimeColumn<R> {
+    DateTimeColumn {
+        title: title.into(),
+        id: Id::default(),
+        value,
+        width: 160.0,
+        now: None,
+        is_relative: false,
+        localization: Localization::default(),
+    }
+}
+
+/// A column of timestamps, displayed as an absolute UTC date & time or relative to now,
+/// e.g. "3 min ago".
+///
+/// The other form is shown in the tooltip of each cell. Absolute timestamps only get a
+/// relative tooltip once the current time is given with [`now`](Self::now). Use
+/// [`value`](Self::value) to sort rows by the underlying timestamp.
+#[derive(Debug, Clone)]
+pub struct DateTimeColumn<R> {
+    title: String,
+    id: Id,
+    value: fn(&R) -> SystemTime,
+    width: f32,
+    now: Option<SystemTime>,
+    is_relative: bool,
+    localization: Localization,
+}
+
+impl<R> DateTimeColumn<R> {
+    /// Sets the initial width of the [`DateTimeColumn`], 160 by default.
+    pub fn width(self, width: f32) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Sets the [`id`](table::Column::id) of the [`DateTimeColumn`].
+    pub fn id(self, id: impl Into<String>) -> Self {
+        Self {
+            id: Id(Some(id.into())),
+            ..self
+        }
+    }
+
+    /// Displays the timestamps relative to `now`.
+    ///
+    /// The time is passed in rather than read from the clock, so the application decides
+    /// how often relative times are refreshed.
+    pub fn relative(self, now: SystemTime) -> Self {
+        Self {
+            now: Some(now),
+            is_relative: true,
+            ..self
+        }
+    }
+
+    /// Sets the current time, used by the tooltips of absolute timestamps.
+    pub fn now(self, now: SystemTime) -> Self {
+        Self {
+            now: Some(now),
+            ..self
+        }
+    }
+
+    /// Sets the [`Localization`] of the relative times, e.g. "3 min ago".
+    pub fn localization(self, localization: Localization) -> Self {
+        Self {
+            localization,
+            ..self
+        }
+    }
+
+    /// Returns the timestamp of the [`DateTimeColumn`] for the given row.
+    pub fn value(&self, row: &R) -> SystemTime {
+        (self.value)(row)
+    }
+
+    fn display(&self, row: &R) -> String {
+        let time = self.value(row);
+
+        match self.now {
+            Some(now) if self.is_relative => relative(time, now, &self.localization),
+            _ => absolute(time),
+        }
+    }
+}
+
+impl<'a, R, Message, Theme, Renderer> table::Column<'a, Message, Theme, Renderer>
+    for DateTimeColumn<R>
+where
+    Theme: widget::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    type Row = R;
+
+    fn header(&'a self, _col_index: usize) -> Element<'a, Message, Theme, Renderer> {
+        widget::text(&self.title).into()
+    }
+
+    fn cell(
+        &'a self,
+        _col_index: usize,
+        _row_index: usize,
+        row: &'a Self::Row,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        widget::text(self.display(row)).into()
+    }
+
+    fn width(&self) -> f32 {
+        self.width
+    }
+
+    fn resize_offset(&self) -> Option<f32> {
+        None
+    }
+
+    fn id(&self, col_index: usize) -> String {
+        self.id.resolve(&self.title, col_index)
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn search_text(&self, row: &Self::Row) -> Option<String> {
+        Some(self.display(row))
+    }
+
+    fn cell_tooltip(&self, row: &Self::Row) -> Option<String> {
+        let time = self.value(row);
+
+        if self.is_relative {
+            Some(absolute(time))
+        } else {
+            self.now.map(|now| relative(time, now, &self.localization))
+        }
+    }
+}
+
+/// Formats a timestamp as "YYYY-MM-DD HH:MM:SS" in UTC.
+fn absolute(time: SystemTime) -> String {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs_f64().ceil() as i64),
+    };
+
+    let days = seconds.div_euclid(86_400);
+    let seconds = seconds.rem_euclid(86_400);
+
+    // Converts days since the epoch to a civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Formats the time elapsed between a timestamp and `now` with the given [`Localization`].
+fn relative(time: SystemTime, now: SystemTime, localization: &Localization) -> String {
+    let (duration, is_future) = match now.duration_since(time) {
+        Ok(duration) => (duration, false),
+        Err(error) => (error.duration(), true),
+    };
+
+    (localization.relative_time)(duration, is_future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn number_column() -> NumberColumn<f64> {
+        NumberColumn::new(String::from("Amount"), |value| *value)
+    }
+
+    #[test]
+    fn format_groups_thousands() {
+        let column = number_column().decimals(2);
+
+        assert_eq!(column.format(1_204.5), "1,204.50");
+        assert_eq!(column.format(1_234_567.0), "1,234,567.00");
+        assert_eq!(column.format(999.0), "999.00");
+    }
+
+    #[test]
+    fn format_negatives() {
+        let column = currency_column("Price", "$", |value: &f64| *value);
+
+        assert_eq!(column.format(-1_204.5), "-$1,204.50");
+        assert_eq!(column.format(-0.5), "-$0.50");
+    }
+
+    #[test]
+    fn format_rounding_to_zero() {
+        let column = currency_column("Price", "$", |value: &f64| *value);
+
+        assert_eq!(column.format(-0.001), "$0.00");
+        assert_eq!(column.format(-0.0), "$0.00");
+    }
+
+    #[test]
+    fn format_custom_separators() {
+        let column = currency_column("Preis", "€", |value: &f64| *value).separators('.', ',');
+
+        assert_eq!(column.format(1_204.5), "€1.204,50");
+        assert_eq!(column.format(-12_345_678.901), "-€12.345.678,90");
+    }
+
+    #[test]
+    fn format_natural_precision() {
+        let column = unit_column("Weight", "kg", |value: &f64| *value);
+
+        assert_eq!(column.format(1.25), "1.25 kg");
+        assert_eq!(column.format(3.0), "3 kg");
+        assert_eq!(column.format(-1_500.5), "-1,500.5 kg");
+    }
+
+    #[test]
+    fn format_sum_without_float_noise() {
+        let column = unit_column("Weight", "kg", |value: &f64| *value);
+
+        assert_eq!(column.format_sum(&[1.1, 2.2]), "3.3 kg");
+        assert_eq!(column.format_sum(&[0.1, 0.25, 0.2]), "0.55 kg");
+        assert_eq!(column.format_sum(&[1.0, 2.0]), "3 kg");
+        assert_eq!(column.format_sum(&[]), "0 kg");
+    }
+
+    #[test]
+    fn format_sum_with_decimals() {
+        let column = currency_column("Price", "$", |value: &f64| *value);
+
+        assert_eq!(column.format_sum(&[1.1, 2.2]), "$3.30");
+    }
+
+    #[test]
+    fn format_not_finite() {
+        let column = number_column();
+
+        assert_eq!(column.format(f64::NAN), "NaN");
+        assert_eq!(column.format(f64::NEG_INFINITY), "-inf");
+    }
+
+    fn at(seconds: i64) -> SystemTime {
+        if seconds >= 0 {
+            UNIX_EPOCH + Duration::from_secs(seconds.unsigned_abs())
+        } else {
+            UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+        }
+    }
+
+    #[test]
+    fn absolute_epoch() {
+        assert_eq!(absolute(UNIX_EPOCH), "1970-01-01 00:00:00");
+    }
+
+    #[test]
+    fn absolute_leap_years() {
+        assert_eq!(absolute(at(951_782_400)), "2000-02-29 00:00:00");
+        assert_eq!(absolute(at(1_709_210_096)), "2024-02-29 12:34:56");
+        assert_eq!(absolute(at(4_107_542_400 - 1)), "2100-02-28 23:59:59");
+    }
+
+    #[test]
+    fn absolute_before_epoch() {
+        assert_eq!(absolute(at(-1)), "1969-12-31 23:59:59");
+        assert_eq!(absolute(at(-14_182_940)), "1969-07-20 20:17:40");
+        assert_eq!(absolute(at(-2_203_891_200 - 1)), "1900-02-28 23:59:59");
+        assert_eq!(
+            absolute(UNIX_EPOCH - Duration::from_millis(500)),
+            "1969-12-31 23:59:59"
+        );
+    }
+
+    #[test]
+    fn relative_boundaries() {
+        let now = at(1_000_000);
+        let localization = Localization::default();
+        let ago = |seconds: i64| relative(at(1_000_000 - seconds), now, &localization);
+
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(59), "just now");
+        assert_eq!(ago(60), "1 min ago");
+        assert_eq!(ago(3_599), "59 min ago");
+        assert_eq!(ago(3_600), "1 h ago");
+        assert_eq!(ago(86_399), "23 h ago");
+        assert_eq!(ago(86_400), "1 d ago");
+        assert_eq!(ago(-59), "just now");
+        assert_eq!(ago(-60), "in 1 min");
+        assert_eq!(ago(-86_400), "in 1 d");
+    }
+}
@@ -0,0 +1,493 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::time::Instant;
+use iced::{
+    event, keyboard, mouse, overlay, window, Color, Element, Length, Point, Rectangle, Size, Vector,
+};
+
+use crate::style;
+use crate::sync;
+use crate::table::ColumnBounds;
+
+// Distance the cursor must travel before a press turns into a drag
+const DRAG_THRESHOLD: f32 = 4.0;
+
+// How quickly the remaining headers slide towards the drop slot
+const SHIFT_SPEED: f32 = 18.0;
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    shifts: Vec<f32>,
+    last_frame: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    slot: usize,
+    origin: Point,
+    position: Point,
+    is_dragging: bool,
+}
+
+/// Wraps the header row and lets the user drag a header to a new position.
+///
+/// While dragging, a floating copy of the header follows the cursor and the
+/// remaining headers slide aside to reveal the drop slot.
+pub(crate) struct Reorder<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    // The bounds of each shown column, in order
+    columns: Vec<ColumnBounds>,
+    on_reorder: fn(usize, usize) -> Message,
+    style: <Theme as style::Catalog>::Style,
+    // Reports an ongoing drag to autoscroll the body
+    sync_drag: sync::Drag,
+}
+
+impl<'a, Message, Theme, Renderer> Reorder<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        columns: Vec<ColumnBounds>,
+        on_reorder: fn(usize, usize) -> Message,
+        style: <Theme as style::Catalog>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            columns,
+            on_reorder,
+            style,
+            sync_drag: sync::Drag::default(),
+        }
+    }
+
+    pub fn drag(mut self, drag: sync::Drag) -> Self {
+        self.sync_drag = drag;
+        self
+    }
+
+    // The bounds of each header, placed like the columns of the table
+    fn header_bounds(&self, layout: Layout<'_>) -> Vec<Rectangle> {
+        let bounds = layout.bounds();
+
+        self.columns
+            .iter()
+            .map(|column| Rectangle {
+                x: bounds.x + column.x,
+                width: column.width,
+                ..bounds
+            })
+            .collect()
+    }
+
+    // Slot the dragged header would land in if released at `x`
+    fn drop_slot(slot: usize, x: f32, headers: &[Rectangle]) -> usize {
+        headers
+            .iter()
+            .enumerate()
+            .filter(|(index, bounds)| *index != slot && bounds.center_x() < x)
+            .count()
+    }
+
+    // Left edge of the drop slot, aligned to the column boundaries
+    fn slot_x(slot: usize, target: usize, headers: &[Rectangle]) -> f32 {
+        if target <= slot {
+            headers[target].x
+        } else {
+            headers[target].x + headers[target].width - headers[slot].width
+        }
+    }
+
+    fn target_shifts(slot: usize, target: usize, headers: &[Rectangle]) -> Vec<f32> {
+        let width = headers[slot].width;
+
+        (0..headers.len())
+            .map(|index| {
+                if slot < target && index > slot && index <= target {
+                    -width
+                } else if target < slot && index >= target && index < slot {
+                    width
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Reorder<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: style::Catalog,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        // Let dividers & interactive headers claim the event first
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let headers = self.header_bounds(layout);
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            self.sync_drag.report(sync::Axis::Horizontal);
+        }
+
+        match event {
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if shell.is_event_captured() {
+                    return;
+                }
+
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    if let Some(slot) = headers.iter().position(|bounds| bounds.contains(position))
+                    {
+                        state.drag = Some(Drag {
+                            slot,
+                            origin: position,
+                            position,
+                            is_dragging: false,
+                        });
+                        state.shifts = vec![0.0; headers.len()];
+                        state.last_frame = None;
+                    }
+                }
+            }
+            event::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((drag, position)) = state.drag.as_mut().zip(cursor.position()) {
+                    drag.position = position;
+
+                    if !drag.is_dragging && position.distance(drag.origin) > DRAG_THRESHOLD {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = state.drag.take() {
+                    if drag.is_dragging && drag.slot < headers.len() {
+                        let target = Self::drop_slot(drag.slot, drag.position.x, &headers);
+
+                        let columns = self.columns[drag.slot]
+                            .index
+                            .zip(self.columns[target].index);
+
+                        if let Some((from, to)) = columns.filter(|_| target != drag.slot) {
+                            shell.publish((self.on_reorder)(from, to));
+                        }
+
+                        shell.capture_event();
+                    }
+
+                    state.shifts.clear();
+                    shell.request_redraw();
+                }
+            }
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                if state.drag.take().is_some() {
+                    state.shifts.clear();
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            event::Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(drag) = state.drag.as_mut().filter(|drag| drag.is_dragging) else {
+                    return;
+                };
+
+                // The header scrolls under a still cursor while autoscrolling, so follow the
+                // translated cursor rather than waiting for it to move
+                if let Some(position) = cursor.position() {
+                    drag.position = position;
+                }
+
+                let drag = *drag;
+
+                if drag.slot >= headers.len() || state.shifts.len() != headers.len() {
+                    return;
+                }
+
+                let elapsed = state
+                    .last_frame
+                    .map(|last| now.saturating_duration_since(last).as_secs_f32())
+                    .unwrap_or_default();
+                state.last_frame = Some(*now);
+
+                let target = Self::drop_slot(drag.slot, drag.position.x, &headers);
+                let targets = Self::target_shifts(drag.slot, target, &headers);
+                let progress = (elapsed * SHIFT_SPEED).min(1.0);
+
+                let mut is_settled = true;
+
+                for (shift, target) in state.shifts.iter_mut().zip(targets) {
+                    if (target - *shift).abs() < 0.5 {
+                        *shift = target;
+                    } else {
+                        *shift += (target - *shift) * progress;
+                        is_settled = false;
+                    }
+                }
+
+                if !is_settled {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            mouse::Interaction::Grabbing
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let content_layout = layout.children().next().unwrap();
+        let headers = self.header_bounds(layout);
+
+        let Some(drag) = state
+            .drag
+            .filter(|drag| drag.is_dragging && drag.slot < headers.len())
+        else {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+            return;
+        };
+
+        // Draw each remaining header at its (animated) shifted position
+        for (index, bounds) in headers.iter().enumerate() {
+            if index == drag.slot {
+                continue;
+            }
+
+            let shift = state.shifts.get(index).copied().unwrap_or_default();
+
+            renderer.with_translation(Vector::new(shift, 0.0), |renderer| {
+                renderer.with_layer(*bounds, |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        style,
+                        content_layout,
+                        Cursor::Unavailable,
+                        viewport,
+                    );
+                });
+            });
+        }
+
+        let dragged = headers[drag.slot];
+        let target = Self::drop_slot(drag.slot, drag.position.x, &headers);
+
+        // Highlight the slot the header will be dropped into
+        let slot_x = Self::slot_x(drag.slot, target, &headers);
+
+        let highlight = theme.divider(&self.style, true);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: slot_x,
+                    ..dragged
+                },
+                border: Default::default(),
+                shadow: Default::default(),
+            },
+            highlight
+                .background
+                .map(|background| background.scale_alpha(0.25))
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+
+        // Floating, semi-transparent copy of the dragged header
+        let offset = drag.position.x - drag.origin.x;
+        let ghost = Rectangle {
+            x: dragged.x + offset,
+            ..dragged
+        };
+        let appearance = theme.header(&self.style);
+        let text_color = appearance.text_color.unwrap_or(style.text_color);
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: ghost,
+                    border: appearance.border,
+                    shadow: iced::Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 8.0,
+                    },
+                },
+                appearance
+                    .background
+                    .map(|background| background.scale_alpha(0.7))
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+
+            renderer.with_translation(Vector::new(offset, 0.0), |renderer| {
+                renderer.with_layer(dragged, |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: text_color.scale_alpha(0.7),
+                        },
+                        content_layout,
+                        Cursor::Unavailable,
+                        viewport,
+                    );
+                });
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Reorder<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(reorder: Reorder<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(reorder)
+    }
+}
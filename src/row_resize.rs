@@ -0,0 +1,225 @@
+use iced::advanced::layout::Layout;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::{event, mouse, Color, Element, Rectangle};
+
+use crate::forward::forward;
+use crate::style;
+use crate::sync::{Axis, Drag};
+
+const GRAB_ZONE: f32 = 4.0;
+const MIN_HEIGHT: f32 = 8.0;
+
+#[derive(Debug, Default)]
+struct State {
+    is_hovered: bool,
+    // The cursor position & height of the content when the drag started
+    drag_origin: Option<(f32, f32)>,
+}
+
+/// Wraps some content and lets its bottom edge be dragged to change its height.
+pub(crate) struct RowResize<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+    style: <Theme as style::Catalog>::Style,
+    drag: Drag,
+}
+
+impl<'a, Message, Theme, Renderer> RowResize<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_resize: impl Fn(f32) -> Message + 'a,
+        style: <Theme as style::Catalog>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_resize: Box::new(on_resize),
+            style,
+            drag: Drag::default(),
+        }
+    }
+
+    pub fn drag(mut self, drag: Drag) -> Self {
+        self.drag = drag;
+        self
+    }
+
+    fn grab_zone(bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            y: bounds.y + bounds.height - GRAB_ZONE,
+            height: GRAB_ZONE,
+            ..bounds
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RowResize<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if state.drag_origin.is_some() {
+            self.drag.report(Axis::Vertical);
+        }
+
+        match event {
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(Self::grab_zone(bounds)) =>
+            {
+                if let Some(position) = cursor.position() {
+                    state.drag_origin = Some((position.y, bounds.height));
+                    shell.capture_event();
+                    return;
+                }
+            }
+            event::Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                // Unlike the event, the cursor is translated along with the scrolled rows
+                let drag = state.drag_origin.zip(cursor.position());
+
+                if let Some(((origin, height), position)) = drag {
+                    shell.publish((self.on_resize)(
+                        (height + position.y - origin).max(MIN_HEIGHT),
+                    ));
+                    shell.capture_event();
+                    return;
+                }
+
+                let is_hovered = cursor.is_over(Self::grab_zone(bounds));
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag_origin.is_some() =>
+            {
+                state.drag_origin = None;
+                shell.request_redraw();
+                shell.capture_event();
+                return;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag_origin.is_some() || cursor.is_over(Self::grab_zone(layout.bounds())) {
+            return mouse::Interaction::ResizingVertically;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_hovered || state.drag_origin.is_some() {
+            let appearance = theme.divider(&self.style, true);
+            let bounds = layout.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - GRAB_ZONE / 2.0,
+                        height: GRAB_ZONE / 2.0,
+                        ..bounds
+                    },
+                    ..Default::default()
+                },
+                appearance
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+        }
+    }
+
+    forward!(children, diff, size, layout, overlay, operate);
+}
+
+impl<'a, Message, Theme, Renderer> From<RowResize<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: style::Catalog + 'a,
+{
+    fn from(row_resize: RowResize<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(row_resize)
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::text::{self as core_text, LineHeight};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::widget::text::{self, Text};
+use iced::{event, mouse, Element, Length, Pixels, Point, Rectangle, Size};
+
+use crate::localization::Localization;
+
+#[derive(Debug, Default)]
+struct State {
+    is_overflowing: bool,
+}
+
+/// Creates a new [`ShowMore`] displaying `content` truncated to `lines` lines, followed by
+/// a "more" link publishing `on_toggle` when the text doesn't fit.
+///
+/// Whether the text is `expanded` is left to the application, e.g. by keeping the indices
+/// of the expanded rows and passing `Message::RowExpanded(index)` as `on_toggle`. Since
+/// rows grow to fit their cells, expanding the text grows just its row.
+pub fn show_more<'a, Message, Theme, Renderer>(
+    content: impl text::IntoFragment<'a>,
+    lines: usize,
+    expanded: bool,
+    on_toggle: Message,
+) -> ShowMore<'a, Message, Theme, Renderer>
+where
+    Theme: text::Catalog + 'a,
+    Renderer: core_text::Renderer,
+{
+    let Localization {
+        show_more,
+        show_less,
+        ..
+    } = Localization::default();
+
+    ShowMore {
+        content: Text::new(content),
+        toggle: Text::new(if expanded { show_less } else { show_more }),
+        lines,
+        expanded,
+        on_toggle,
+        size: None,
+    }
+}
+
+/// A text truncated to a number of lines that can be expanded by clicking on its link.
+pub struct ShowMore<'a, Message, Theme, Renderer>
+where
+    Theme: text::Catalog,
+    Renderer: core_text::Renderer,
+{
+    content: Text<'a, Theme, Renderer>,
+    toggle: Text<'a, Theme, Renderer>,
+    lines: usize,
+    expanded: bool,
+    on_toggle: Message,
+    size: Option<Pixels>,
+}
+
+impl<'a, Message, Theme, Renderer> fmt::Debug for ShowMore<'a, Message, Theme, Renderer>
+where
+    Theme: text::Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShowMore")
+            .field("lines", &self.lines)
+            .field("expanded", &self.expanded)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> ShowMore<'a, Message, Theme, Renderer>
+where
+    Theme: text::Catalog + 'a,
+    Renderer: core_text::Renderer,
+{
+    /// Sets the size of the text of the [`ShowMore`].
+    pub fn size(self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        Self {
+            content: self.content.size(size),
+            toggle: self.toggle.size(size),
+            size: Some(size),
+            ..self
+        }
+    }
+
+    /// Sets the labels of the link from the [`show_more`](Localization::show_more) &
+    /// [`show_less`](Localization::show_less) of a [`Localization`].
+    pub fn localization(self, localization: &Localization) -> Self {
+        self.labels(
+            localization.show_more.clone(),
+            localization.show_less.clone(),
+        )
+    }
+
+    /// Sets the labels of the link expanding & collapsing the text, "more" & "less" by
+    /// default.
+    pub fn labels(
+        self,
+        more: impl text::IntoFragment<'a>,
+        less: impl text::IntoFragment<'a>,
+    ) -> Self {
+        let toggle = Text::new(if self.expanded {
+            less.into_fragment()
+        } else {
+            more.into_fragment()
+        });
+
+        Self {
+            toggle: match self.size {
+                Some(size) => toggle.size(size),
+                None => toggle,
+            },
+            ..self
+        }
+    }
+
+    fn content(&self) -> &dyn Widget<Message, Theme, Renderer> {
+        &self.content
+    }
+
+    fn toggle(&self) -> &dyn Widget<Message, Theme, Renderer> {
+        &self.toggle
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ShowMore<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: text::Catalog + 'a,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![
+            widget::Tree::new(self.content()),
+            widget::Tree::new(self.toggle()),
+        ]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[self.content(), self.toggle()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let text_limits =
+            layout::Limits::new(Size::ZERO, Size::new(limits.max().width, f32::INFINITY));
+
+        let content = self
+            .content()
+            .layout(&mut tree.children[0], renderer, &text_limits);
+
+        let line_height = LineHeight::default()
+            .to_absolute(self.size.unwrap_or_else(|| renderer.default_size()))
+            .0;
+        let max_height = self.lines as f32 * line_height;
+
+        // A fraction of a pixel is left for rounding in the text layout
+        let is_overflowing = content.size().height > max_height + 0.5;
+        tree.state.downcast_mut::<State>().is_overflowing = is_overflowing;
+
+        if !is_overflowing {
+            let size = limits.resolve(Length::Fill, Length::Shrink, content.size());
+
+            return layout::Node::with_children(size, vec![content, layout::Node::default()]);
+        }
+
+        let height = if self.expanded {
+            content.size().height
+        } else {
+            max_height
+        };
+
+        let toggle = self
+            .toggle()
+            .layout(&mut tree.children[1], renderer, &text_limits)
+            .move_to(Point::new(0.0, height));
+
+        let size = limits.resolve(
+            Length::Fill,
+            Length::Shrink,
+            Size::new(
+                content.size().width.max(toggle.size().width),
+                height + toggle.size().height,
+            ),
+        );
+
+        layout::Node::with_children(size, vec![content, toggle])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !tree.state.downcast_ref::<State>().is_overflowing {
+            return;
+        }
+
+        if let event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+            let toggle = layout.children().nth(1).unwrap();
+
+            if cursor.is_over(toggle.bounds()) {
+                shell.publish(self.on_toggle.clone());
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let toggle = layout.children().nth(1).unwrap();
+
+        if tree.state.downcast_ref::<State>().is_overflowing && cursor.is_over(toggle.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let content = children.next().unwrap();
+        let toggle = children.next().unwrap();
+
+        if !tree.state.downcast_ref::<State>().is_overflowing {
+            self.content().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                viewport,
+            );
+
+            return;
+        }
+
+        let bounds = layout.bounds();
+
+        // The truncated lines are clipped just above the link
+        let clip = Rectangle {
+            height: toggle.bounds().y - bounds.y,
+            ..bounds
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            self.content().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                viewport,
+            );
+        });
+
+        self.toggle().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            toggle,
+            cursor,
+            viewport,
+        );
+
+        // Underline the link so it reads as clickable
+        let toggle = toggle.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: toggle.y + toggle.height - 1.0,
+                    height: 1.0,
+                    ..toggle
+                },
+                ..Default::default()
+            },
+            style.text_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ShowMore<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: text::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(show_more: ShowMore<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(show_more)
+    }
+}
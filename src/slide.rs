@@ -0,0 +1,286 @@
+use iced::advanced::layout::Layout;
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::time::{Duration, Instant};
+use iced::{event, window, Element, Rectangle, Vector};
+
+use crate::forward::forward;
+
+// How long the remaining columns take to close the gap of hidden columns
+const SLIDE_DURATION: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Default)]
+struct State {
+    // The id & width of the columns shown by the last view
+    columns: Vec<(String, f32)>,
+    gaps: Option<Gaps>,
+}
+
+#[derive(Debug)]
+struct Gaps {
+    // The offset from the left edge of the content & total width of each run of
+    // adjacent hidden columns, in order
+    runs: Vec<(f32, f32)>,
+    // Set on the first frame after the columns were hidden
+    start: Option<Instant>,
+    progress: f32,
+}
+
+/// Wraps the headers, rows or footers of the table and slides the columns following a
+/// hidden column to the left, closing its gap instead of jumping into place.
+///
+/// Each run of adjacent hidden columns leaves its own gap, so the columns between two
+/// runs only slide by the width of the runs before them.
+pub(crate) struct Slide<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    // The id & width of each shown column, in order
+    columns: Vec<(String, f32)>,
+    reduce_motion: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Slide<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        columns: Vec<(String, f32)>,
+        reduce_motion: bool,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            columns,
+            reduce_motion,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Slide<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            columns: self.columns.clone(),
+            gaps: None,
+        })
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content]);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.columns != self.columns {
+            if let Some(runs) = gaps(&state.columns, &self.columns) {
+                if !self.reduce_motion {
+                    state.gaps = Some(Gaps {
+                        runs,
+                        start: None,
+                        progress: 0.0,
+                    });
+                }
+            }
+
+            state.columns.clone_from(&self.columns);
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let event::Event::Window(window::Event::RedrawRequested(now)) = event {
+            if let Some(gaps) = &mut state.gaps {
+                let start = *gaps.start.get_or_insert(*now);
+
+                gaps.progress = now.saturating_duration_since(start).as_secs_f32()
+                    / SLIDE_DURATION.as_secs_f32();
+
+                if gaps.progress >= 1.0 {
+                    state.gaps = None;
+                }
+
+                shell.request_redraw();
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let content = layout.children().next().unwrap();
+
+        let Some(gaps) = &tree.state.downcast_ref::<State>().gaps else {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content,
+                cursor,
+                viewport,
+            );
+            return;
+        };
+
+        let bounds = layout.bounds();
+
+        // Eases out, so the columns slow down as they reach their place
+        let remaining = (1.0 - gaps.progress.clamp(0.0, 1.0)).powi(3);
+
+        // The columns before the first gap stay in place, while the ones following each
+        // gap are drawn shifted right by what's left of it & of the gaps before it
+        let mut segments = vec![(0.0, 0.0)];
+        let mut hidden = 0.0;
+
+        for (x, width) in &gaps.runs {
+            segments.push((x - hidden, (hidden + width) * remaining));
+            hidden += width;
+        }
+
+        for (i, &(start, offset)) in segments.iter().enumerate() {
+            let end = segments.get(i + 1).map_or(bounds.width, |(next, _)| *next);
+
+            // Clip to where the segment is drawn, so it doesn't bleed into the gap
+            let clip = Rectangle {
+                x: bounds.x + start + offset,
+                width: (end - start).max(0.0),
+                ..bounds
+            };
+            let offset = Vector::new(offset, 0.0);
+
+            if let Some(clip) = clip.intersection(viewport) {
+                renderer.with_layer(clip, |renderer| {
+                    renderer.with_translation(offset, |renderer| {
+                        self.content.as_widget().draw(
+                            &tree.children[0],
+                            renderer,
+                            theme,
+                            style,
+                            content,
+                            cursor,
+                            &(*viewport - offset),
+                        );
+                    });
+                });
+            }
+        }
+    }
+
+    forward!(children, size, layout, mouse_interaction, overlay, operate);
+}
+
+impl<'a, Message, Theme, Renderer> From<Slide<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(slide: Slide<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(slide)
+    }
+}
+
+// Returns the offset & total width of each run of adjacent columns of `previous` missing
+// from `current`, unless columns were also shown or reordered
+fn gaps(previous: &[(String, f32)], current: &[(String, f32)]) -> Option<Vec<(f32, f32)>> {
+    let mut remaining = current.iter().peekable();
+    let mut runs: Vec<(f32, f32)> = Vec::new();
+    let mut is_hiding = false;
+    let mut x = 0.0;
+
+    for (id, width) in previous {
+        if remaining.next_if(|(next, _)| next == id).is_none() {
+            match runs.last_mut() {
+                Some((_, run)) if is_hiding => *run += width,
+                _ => runs.push((x, *width)),
+            }
+
+            is_hiding = true;
+        } else {
+            is_hiding = false;
+        }
+
+        x += width;
+    }
+
+    if remaining.peek().is_some() || runs.is_empty() {
+        return None;
+    }
+
+    Some(runs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(ids: &[&str]) -> Vec<(String, f32)> {
+        ids.iter().map(|id| (id.to_string(), 100.0)).collect()
+    }
+
+    #[test]
+    fn adjacent_hidden_columns_share_a_gap() {
+        let previous = columns(&["a", "b", "c", "d"]);
+        let current = columns(&["a", "d"]);
+
+        assert_eq!(gaps(&previous, &current), Some(vec![(100.0, 200.0)]));
+    }
+
+    #[test]
+    fn separate_hidden_columns_leave_a_gap_each() {
+        let previous = columns(&["a", "b", "c", "d", "e"]);
+        let current = columns(&["a", "c", "e"]);
+
+        assert_eq!(
+            gaps(&previous, &current),
+            Some(vec![(100.0, 100.0), (300.0, 100.0)])
+        );
+    }
+
+    #[test]
+    fn shown_or_reordered_columns_leave_no_gap() {
+        let previous = columns(&["a", "b", "c"]);
+
+        assert_eq!(gaps(&previous, &columns(&["a", "b", "c", "d"])), None);
+        assert_eq!(gaps(&previous, &columns(&["b", "a"])), None);
+    }
+}
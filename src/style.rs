@@ -1,4 +1,26 @@
-use iced::widget::container;
+use iced::widget::{container, scrollable};
+use iced::{Border, Color};
+
+/// A variant of the divider of a column, see
+/// [`Column::divider_style`](crate::table::Column::divider_style).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DividerVariant {
+    /// A divider standing out from the others, e.g. between groups of columns.
+    Emphasized,
+    /// A divider blending in with the rows.
+    Subtle,
+}
+
+/// The line separating the header or footer from the body of a [`Table`](crate::Table).
+///
+/// A separator with a `width` of zero is not drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Separator {
+    /// The thickness of the line.
+    pub width: f32,
+    /// The color of the line.
+    pub color: Color,
+}
 
 /// A set of rules that dictate the styling of a [`Table`](crate::Table).
 pub trait Catalog {
@@ -13,6 +35,103 @@ pub trait Catalog {
     fn row(&self, style: &Self::Style, index: usize) -> container::Style;
     /// The divider [`Style`](iced_widget::container::Style) of the [`Catalog`].
     fn divider(&self, style: &Self::Style, hovered: bool) -> container::Style;
+    /// The [`Style`](iced_widget::container::Style) of the divider of a column using a
+    /// [`DividerVariant`].
+    ///
+    /// Defaults to the regular [`divider`](Self::divider) style.
+    fn divider_variant(
+        &self,
+        style: &Self::Style,
+        _variant: DividerVariant,
+        hovered: bool,
+    ) -> container::Style {
+        self.divider(style, hovered)
+    }
+    /// The [`Style`](iced_widget::scrollable::Style) of the scrollbars of the body.
+    ///
+    /// Defaults to the default style of the [`scrollable::Catalog`].
+    fn scrollbar(&self, _style: &Self::Style, status: scrollable::Status) -> scrollable::Style
+    where
+        Self: scrollable::Catalog + Sized,
+    {
+        scrollable::Catalog::style(self, &<Self as scrollable::Catalog>::default(), status)
+    }
+    /// The [`Style`](iced_widget::container::Style) of the tooltips of the cells, see
+    /// [`Column::cell_tooltip`](crate::table::Column::cell_tooltip).
+    ///
+    /// Defaults to the [`resize_label`](Self::resize_label) style.
+    fn tooltip(&self, style: &Self::Style) -> container::Style {
+        self.resize_label(style)
+    }
+    /// The [`Style`](iced_widget::container::Style) of the chips of a
+    /// [`tags_cell`](crate::tags_cell).
+    fn tag(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            background: Some(Color::from_rgba(0.5, 0.5, 0.5, 0.2).into()),
+            border: Border::default().rounded(8),
+            ..Default::default()
+        }
+    }
+    /// The [`Color`] of a [`link_cell`](crate::link_cell).
+    fn link(&self, _style: &Self::Style) -> Color {
+        Color::from_rgb(0.1, 0.4, 0.9)
+    }
+    /// The [`Style`](iced_widget::container::Style) of the placeholders standing in for
+    /// cells beyond the [`render_budget`](crate::Table::render_budget) of a frame.
+    fn placeholder(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            background: Some(Color::from_rgba(0.5, 0.5, 0.5, 0.15).into()),
+            border: Border::default().rounded(2),
+            ..Default::default()
+        }
+    }
+    /// The [`Separator`] drawn along the bottom edge of the header.
+    fn header_separator(&self, _style: &Self::Style) -> Separator {
+        Separator::default()
+    }
+    /// The [`Separator`] drawn along the top edge of the footer.
+    fn footer_separator(&self, _style: &Self::Style) -> Separator {
+        Separator::default()
+    }
+    /// The [`Style`](iced_widget::container::Style) used to emphasize a whole row, e.g. the
+    /// current match of the find bar.
+    fn row_highlight(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            background: Some(Color::from_rgba(1.0, 0.8, 0.0, 0.25).into()),
+            ..Default::default()
+        }
+    }
+    /// The [`Style`](iced_widget::container::Style) used to emphasize a single cell, e.g. the
+    /// cells matching the query of the find bar.
+    fn cell_highlight(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            background: Some(Color::from_rgba(1.0, 0.8, 0.0, 0.35).into()),
+            border: Border::default()
+                .width(1)
+                .color(Color::from_rgb(1.0, 0.7, 0.0)),
+            ..Default::default()
+        }
+    }
+    /// The [`Style`](iced_widget::container::Style) outlining the cell moved by keyboard
+    /// navigation, e.g. with Home or Page Down.
+    fn focused_cell(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            border: Border::default()
+                .width(2)
+                .color(Color::from_rgb(0.2, 0.5, 1.0)),
+            ..Default::default()
+        }
+    }
+    /// The [`Style`](iced_widget::container::Style) of the width readout shown while a column
+    /// is being resized.
+    fn resize_label(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            text_color: Some(Color::WHITE),
+            background: Some(Color::from_rgba(0.0, 0.0, 0.0, 0.75).into()),
+            border: Border::default().rounded(4),
+            ..Default::default()
+        }
+    }
 }
 
 impl Catalog for iced::Theme {
@@ -56,13 +175,124 @@ impl Catalog for iced::Theme {
             ..Default::default()
         }
     }
+
+    fn divider_variant(
+        &self,
+        style: &Self::Style,
+        variant: DividerVariant,
+        hovered: bool,
+    ) -> container::Style {
+        if hovered {
+            return self.divider(style, hovered);
+        }
+
+        let color = match variant {
+            DividerVariant::Emphasized => self.extended_palette().background.strong.text,
+            DividerVariant::Subtle => Color {
+                a: 0.5,
+                ..self.extended_palette().background.weak.color
+            },
+        };
+
+        container::Style {
+            background: Some(color.into()),
+            ..Default::default()
+        }
+    }
+
+    fn scrollbar(&self, _style: &Self::Style, status: scrollable::Status) -> scrollable::Style {
+        let palette = self.extended_palette();
+        let style = scrollable::default(self, status);
+
+        let rail = |rail: scrollable::Rail| scrollable::Rail {
+            background: Some(palette.background.base.color.into()),
+            ..rail
+        };
+
+        scrollable::Style {
+            vertical_rail: rail(style.vertical_rail),
+            horizontal_rail: rail(style.horizontal_rail),
+            gap: Some(palette.background.strong.color.into()),
+            ..style
+        }
+    }
+
+    fn tag(&self, _style: &Self::Style) -> container::Style {
+        let pair = self.extended_palette().secondary.weak;
+
+        container::Style {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            border: Border::default().rounded(8),
+            ..Default::default()
+        }
+    }
+
+    fn link(&self, _style: &Self::Style) -> Color {
+        self.extended_palette().primary.base.color
+    }
+
+    fn placeholder(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            background: Some(self.extended_palette().background.weak.color.into()),
+            border: Border::default().rounded(2),
+            ..Default::default()
+        }
+    }
+
+    fn row_highlight(&self, _style: &Self::Style) -> container::Style {
+        let color = self.extended_palette().primary.weak.color;
+
+        container::Style {
+            background: Some(Color { a: 0.35, ..color }.into()),
+            ..Default::default()
+        }
+    }
+
+    fn cell_highlight(&self, _style: &Self::Style) -> container::Style {
+        let palette = self.extended_palette();
+
+        container::Style {
+            background: Some(
+                Color {
+                    a: 0.5,
+                    ..palette.primary.weak.color
+                }
+                .into(),
+            ),
+            border: Border::default()
+                .width(1)
+                .color(palette.primary.strong.color),
+            ..Default::default()
+        }
+    }
+
+    fn focused_cell(&self, _style: &Self::Style) -> container::Style {
+        container::Style {
+            border: Border::default()
+                .width(2)
+                .color(self.extended_palette().primary.strong.color),
+            ..Default::default()
+        }
+    }
+
+    fn resize_label(&self, _style: &Self::Style) -> container::Style {
+        let pair = self.extended_palette().primary.strong;
+
+        container::Style {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            border: Border::default().rounded(4),
+            ..Default::default()
+        }
+    }
 }
 
 pub(crate) mod wrapper {
-    use iced::{mouse::Cursor, Color, Element, Length, Size, Vector};
+    use iced::advanced::renderer;
     use iced::advanced::widget::Widget;
     use iced::widget::container;
-    use iced::advanced::renderer;
+    use iced::{mouse::Cursor, Color, Element, Length, Rectangle, Size, Vector};
 
     pub fn header<'a, Message, Theme, Renderer>(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -98,6 +328,41 @@ pub(crate) mod wrapper {
         .into()
     }
 
+    pub fn frame<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        style: <Theme as super::Catalog>::Style,
+        radius: f32,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: renderer::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Frame { radius },
+            style,
+        }
+        .into()
+    }
+
+    pub fn tooltip<'a, Message, Theme, Renderer>(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        style: <Theme as super::Catalog>::Style,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Renderer: renderer::Renderer + 'a,
+        Theme: super::Catalog + 'a,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::Tooltip,
+            style,
+        }
+        .into()
+    }
+
     pub fn row<'a, Message, Theme, Renderer>(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
         style: <Theme as super::Catalog>::Style,
@@ -119,6 +384,8 @@ pub(crate) mod wrapper {
     enum Target {
         Header,
         Footer,
+        Frame { radius: f32 },
+        Tooltip,
         Row { index: usize },
     }
 
@@ -134,9 +401,46 @@ pub(crate) mod wrapper {
             match self {
                 Target::Header => theme.header(style),
                 Target::Footer => theme.footer(style),
+                Target::Frame { radius } => {
+                    let appearance = theme.header(style);
+
+                    container::Style {
+                        border: appearance.border.rounded(*radius),
+                        ..appearance
+                    }
+                }
+                Target::Tooltip => theme.tooltip(style),
                 Target::Row { index } => theme.row(style, *index),
             }
         }
+
+        fn separator<Theme>(
+            &self,
+            theme: &Theme,
+            style: &<Theme as super::Catalog>::Style,
+            bounds: Rectangle,
+        ) -> Option<(Rectangle, super::Separator)>
+        where
+            Theme: super::Catalog,
+        {
+            let (separator, y) = match self {
+                Target::Header => {
+                    let separator = theme.header_separator(style);
+                    (separator, bounds.y + bounds.height - separator.width)
+                }
+                Target::Footer => (theme.footer_separator(style), bounds.y),
+                Target::Frame { .. } | Target::Tooltip | Target::Row { .. } => return None,
+            };
+
+            (separator.width > 0.0).then_some((
+                Rectangle {
+                    y,
+                    height: separator.width,
+                    ..bounds
+                },
+                separator,
+            ))
+        }
     }
 
     struct Wrapper<'a, Message, Theme, Renderer>
@@ -198,7 +502,20 @@ pub(crate) mod wrapper {
 
             self.content
                 .as_widget()
-                .draw(state, renderer, theme, &style, layout, cursor, viewport)
+                .draw(state, renderer, theme, &style, layout, cursor, viewport);
+
+            if let Some((bounds, separator)) =
+                self.target
+                    .separator::<Theme>(theme, &self.style, layout.bounds())
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..Default::default()
+                    },
+                    separator.color,
+                );
+            }
         }
 
         fn tag(&self) -> iced::advanced::widget::tree::Tag {
@@ -0,0 +1,685 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::operation::{self, Operation};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Shell};
+use iced::mouse::Cursor;
+use iced::time::Instant;
+use iced::widget::scrollable::AbsoluteOffset;
+use iced::{
+    event, keyboard, mouse, overlay, touch, window, Element, Length, Rectangle, Size, Vector,
+};
+
+use crate::body::{MoveFocus, Navigation};
+
+// Distance scrolled per arrow key press or wheel line, matching iced's scrollable
+const SCROLL_STEP: f32 = 60.0;
+// Distance from the edges of the body where dragging starts autoscrolling
+const AUTOSCROLL_EDGE: f32 = 40.0;
+// Distance autoscrolled per second when the cursor reaches the edge
+const AUTOSCROLL_SPEED: f32 = 1200.0;
+
+#[derive(Debug, Default)]
+struct State {
+    offset: Option<f32>,
+    modifiers: keyboard::Modifiers,
+    autoscroll: Vector,
+    // The last frame autoscrolled, so the speed doesn't depend on the frame rate
+    last_frame: Option<Instant>,
+    // Whether the table was clicked last, rather than something else of the window
+    is_focused: bool,
+}
+
+/// The direction a widget of the table is being dragged in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Axis {
+    /// Resizing or reordering columns.
+    Horizontal,
+    /// Resizing rows.
+    Vertical,
+}
+
+/// Shared between the [`ScrollSync`] & the widgets autoscrolling the body while dragged.
+///
+/// A dragged widget reports it on every event it receives, so the flag never outlives
+/// the drag.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Drag(Rc<Cell<Option<Axis>>>);
+
+impl Drag {
+    pub fn report(&self, axis: Axis) {
+        self.0.set(Some(axis));
+    }
+
+    fn take(&self) -> Option<Axis> {
+        self.0.take()
+    }
+}
+
+/// Wraps the header, body & footer and keeps the horizontal offset of the
+/// header & footer in sync with the body within the same frame.
+pub(crate) struct ScrollSync<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    body: widget::Id,
+    targets: Vec<widget::Id>,
+    drag: Drag,
+}
+
+impl<'a, Message, Theme, Renderer> ScrollSync<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        body: widget::Id,
+        targets: Vec<widget::Id>,
+        drag: Drag,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            body,
+            targets,
+            drag,
+        }
+    }
+
+    // Returns the bounds & current horizontal offset of the body scrollable
+    fn body(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<(Rectangle, f32)> {
+        let mut find = FindBody {
+            target: self.body.clone(),
+            body: None,
+            skip_children: false,
+        };
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut find,
+        );
+
+        find.body
+    }
+
+    fn scroll_body(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        offset: AbsoluteOffset,
+    ) {
+        let mut scroll = ScrollBody {
+            target: self.body.clone(),
+            offset,
+            skip_children: false,
+        };
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut scroll,
+        );
+    }
+
+    // Moves the focused cell of the body, scrolling to it if the page left it behind
+    fn move_focus(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        navigation: Navigation,
+    ) {
+        let mut focus = MoveFocus::new(self.body.clone(), navigation);
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut focus,
+        );
+
+        if let operation::Outcome::Chain(mut scroll) = focus.finish() {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                scroll.as_mut(),
+            );
+        }
+    }
+
+    // Scrolls the body while a column is dragged near its left or right edge, or a row near
+    // its top or bottom edge, faster the closer the cursor gets to the edge
+    fn autoscroll(
+        &self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let axis = self.drag.take();
+
+        match event {
+            event::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some((bounds, _)) = self.body(tree, layout, renderer) else {
+                    return;
+                };
+
+                let proximity = |distance: f32| (1.0 - distance / AUTOSCROLL_EDGE).clamp(0.0, 1.0);
+
+                let autoscroll = match axis {
+                    Some(Axis::Horizontal) => Vector::new(
+                        proximity(bounds.x + bounds.width - position.x)
+                            - proximity(position.x - bounds.x),
+                        0.0,
+                    ),
+                    Some(Axis::Vertical) => Vector::new(
+                        0.0,
+                        proximity(bounds.y + bounds.height - position.y)
+                            - proximity(position.y - bounds.y),
+                    ),
+                    None => Vector::ZERO,
+                } * AUTOSCROLL_SPEED;
+
+                let state = tree.state.downcast_mut::<State>();
+                state.autoscroll = autoscroll;
+
+                if autoscroll != Vector::ZERO {
+                    shell.request_redraw();
+                } else {
+                    state.last_frame = None;
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let state = tree.state.downcast_mut::<State>();
+                state.autoscroll = Vector::ZERO;
+                state.last_frame = None;
+            }
+            event::Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                let autoscroll = state.autoscroll;
+
+                if autoscroll != Vector::ZERO {
+                    let elapsed = state
+                        .last_frame
+                        .map(|last| now.saturating_duration_since(last).as_secs_f32())
+                        .unwrap_or_default();
+                    state.last_frame = Some(*now);
+
+                    let offset = AbsoluteOffset {
+                        x: autoscroll.x * elapsed,
+                        y: autoscroll.y * elapsed,
+                    };
+
+                    self.scroll_body(tree, layout, renderer, offset);
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn scroll_targets(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        x: f32,
+    ) {
+        let mut scroll = ScrollTargets {
+            targets: self.targets.clone(),
+            x,
+            skip_children: false,
+        };
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut scroll,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ScrollSync<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_autoscrolling = tree.state.downcast_ref::<State>().autoscroll != Vector::ZERO;
+
+        // Dragged widgets report themselves again while handling this event
+        self.drag.take();
+
+        // Input & autoscrolling move the body right away, while operations like `scroll_to`
+        // are caught up with before the next frame is drawn. Other events can't move it, so
+        // the tree traversal is skipped
+        if !is_autoscrolling
+            && !matches!(
+                event,
+                event::Event::Mouse(_)
+                    | event::Event::Touch(_)
+                    | event::Event::Keyboard(_)
+                    | event::Event::Window(window::Event::RedrawRequested(_))
+            )
+        {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+            return;
+        }
+
+        if let event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            tree.state.downcast_mut::<State>().modifiers = *modifiers;
+        }
+
+        if let event::Event::Mouse(mouse::Event::ButtonPressed(_))
+        | event::Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            tree.state.downcast_mut::<State>().is_focused = cursor.is_over(layout.bounds());
+        }
+
+        let is_over_body = self
+            .body(tree, layout, renderer)
+            .is_some_and(|(bounds, _)| cursor.is_over(bounds));
+
+        let modifiers = tree.state.downcast_ref::<State>().modifiers;
+
+        match event {
+            // Shift + wheel scrolls the body horizontally
+            event::Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if modifiers.shift() && is_over_body =>
+            {
+                let x = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * SCROLL_STEP,
+                    mouse::ScrollDelta::Pixels { y, .. } => -y,
+                };
+
+                self.scroll_body(tree, layout, renderer, AbsoluteOffset { x, y: 0.0 });
+                shell.capture_event();
+            }
+            // The header & footer forward the wheel to the body, which they follow
+            event::Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if !is_over_body && cursor.is_over(layout.bounds()) =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (-x * SCROLL_STEP, -y * SCROLL_STEP),
+                    mouse::ScrollDelta::Pixels { x, y } => (-x, -y),
+                };
+
+                let offset = if modifiers.shift() {
+                    AbsoluteOffset { x: x + y, y: 0.0 }
+                } else {
+                    AbsoluteOffset { x, y }
+                };
+
+                self.scroll_body(tree, layout, renderer, offset);
+                shell.capture_event();
+            }
+            _ => {
+                self.content.as_widget_mut().update(
+                    &mut tree.children[0],
+                    event,
+                    layout.children().next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        if let event::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modifiers,
+            ..
+        }) = event
+        {
+            let page = self
+                .body(tree, layout, renderer)
+                .map_or(0.0, |(bounds, _)| bounds.height);
+
+            // Scrolling past the edges is clamped, so infinity reaches them
+            let edge = f32::INFINITY;
+
+            let (x, y) = match key {
+                keyboard::key::Named::ArrowLeft => (-SCROLL_STEP, 0.0),
+                keyboard::key::Named::ArrowRight => (SCROLL_STEP, 0.0),
+                keyboard::key::Named::PageUp => (0.0, -page),
+                keyboard::key::Named::PageDown => (0.0, page),
+                keyboard::key::Named::Home if modifiers.command() => (-edge, -edge),
+                keyboard::key::Named::End if modifiers.command() => (edge, edge),
+                keyboard::key::Named::Home => (0.0, -edge),
+                keyboard::key::Named::End => (0.0, edge),
+                _ => (0.0, 0.0),
+            };
+
+            let navigation = match key {
+                keyboard::key::Named::PageUp => Some(Navigation::PageUp),
+                keyboard::key::Named::PageDown => Some(Navigation::PageDown),
+                keyboard::key::Named::Home if modifiers.command() => Some(Navigation::FirstCell),
+                keyboard::key::Named::End if modifiers.command() => Some(Navigation::LastCell),
+                keyboard::key::Named::Home => Some(Navigation::FirstRow),
+                keyboard::key::Named::End => Some(Navigation::LastRow),
+                _ => None,
+            };
+
+            // The arrows scroll the hovered table, while navigating moves the focus of the
+            // table clicked last
+            let is_target = if navigation.is_some() {
+                tree.state.downcast_ref::<State>().is_focused
+            } else {
+                cursor.is_over(layout.bounds())
+            };
+
+            if (x != 0.0 || y != 0.0) && !shell.is_event_captured() && is_target {
+                self.scroll_body(tree, layout, renderer, AbsoluteOffset { x, y });
+
+                if let Some(navigation) = navigation {
+                    self.move_focus(tree, layout, renderer, navigation);
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+        }
+
+        self.autoscroll(tree, event, layout, renderer, shell);
+
+        let Some((_, offset)) = self.body(tree, layout, renderer) else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.offset != Some(offset) {
+            state.offset = Some(offset);
+
+            self.scroll_targets(tree, layout, renderer, offset);
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ScrollSync<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(sync: ScrollSync<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(sync)
+    }
+}
+
+// Reads the bounds & horizontal offset of the target scrollable
+struct FindBody {
+    target: widget::Id,
+    body: Option<(Rectangle, f32)>,
+    skip_children: bool,
+}
+
+impl Operation for FindBody {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        // Nothing of interest lives inside the scrollables themselves
+        if self.body.is_none() && !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn operation::Scrollable,
+    ) {
+        if id == Some(&self.target) {
+            self.body = Some((bounds, translation.x));
+        }
+
+        self.skip_children = true;
+    }
+}
+
+// Scrolls the target scrollable by the given offset
+struct ScrollBody {
+    target: widget::Id,
+    offset: AbsoluteOffset,
+    skip_children: bool,
+}
+
+impl Operation for ScrollBody {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        _translation: Vector,
+        state: &mut dyn operation::Scrollable,
+    ) {
+        if id == Some(&self.target) {
+            state.scroll_by(self.offset, bounds, content_bounds);
+        }
+
+        self.skip_children = true;
+    }
+}
+
+// Scrolls every target scrollable to the given horizontal offset
+struct ScrollTargets {
+    targets: Vec<widget::Id>,
+    x: f32,
+    skip_children: bool,
+}
+
+impl Operation for ScrollTargets {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+        state: &mut dyn operation::Scrollable,
+    ) {
+        if id.is_some_and(|id| self.targets.contains(id)) {
+            state.scroll_to(AbsoluteOffset { x: self.x, y: 0.0 });
+        }
+
+        self.skip_children = true;
+    }
+}
+
+// Scrolls the target scrollable so the given content position is vertically centered
+pub(crate) struct ScrollTo {
+    target: widget::Id,
+    y: f32,
+    skip_children: bool,
+}
+
+impl ScrollTo {
+    pub fn new(target: widget::Id, y: f32) -> Self {
+        Self {
+            target,
+            y,
+            skip_children: false,
+        }
+    }
+}
+
+impl Operation for ScrollTo {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if !std::mem::take(&mut self.skip_children) {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+        state: &mut dyn operation::Scrollable,
+    ) {
+        if id == Some(&self.target) {
+            let max_y = (content_bounds.height - bounds.height).max(0.0);
+
+            state.scroll_to(AbsoluteOffset {
+                x: translation.x,
+                y: (self.y - bounds.height / 2.0).clamp(0.0, max_y),
+            });
+        }
+
+        self.skip_children = true;
+    }
+}
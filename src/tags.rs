@@ -0,0 +1,463 @@
+use std::fmt;
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::text::{self, Paragraph, Text};
+use iced::advanced::widget::{self, Widget};
+use iced::advanced::{renderer, Clipboard, Overlay, Shell};
+use iced::mouse::Cursor;
+use iced::{
+    alignment, event, mouse, overlay, Color, Element, Font, Length, Pixels, Point, Rectangle, Size,
+    Vector,
+};
+
+use crate::style;
+
+const PADDING_X: f32 = 6.0;
+const PADDING_Y: f32 = 2.0;
+const SPACING: f32 = 4.0;
+
+#[derive(Debug, Default)]
+struct State {
+    widths: Vec<f32>,
+    visible: usize,
+    is_overflow_hovered: bool,
+}
+
+/// Creates a new [`Tags`] cell laying out `tags` as chips.
+///
+/// The tags that don't fit the width of the column are collapsed into a "+N" chip, listing
+/// them in a popover while hovered. The chips fill the width the table gives the cell, so
+/// they follow the width of the column & the [`scale`](crate::Table::scale) of the table.
+///
+/// Like the rest of the content of the cells, their text isn't scaled by the table and
+/// uses the default font & size of the renderer. Set the [`size`](Tags::size) &
+/// [`font`](Tags::font) of the [`Tags`] to match the other cells.
+pub fn tags_cell<'a, Theme>(tags: &'a [String]) -> Tags<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    Tags {
+        tags,
+        label: Label::default(),
+        style: Default::default(),
+    }
+}
+
+/// A list of tags displayed as chips, see [`tags_cell`].
+pub struct Tags<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    tags: &'a [String],
+    label: Label,
+    style: <Theme as style::Catalog>::Style,
+}
+
+impl<'a, Theme> fmt::Debug for Tags<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tags")
+            .field("tags", &self.tags)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Theme> Tags<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    /// Sets the size of the text of the [`Tags`].
+    pub fn size(self, size: impl Into<Pixels>) -> Self {
+        Self {
+            label: Label {
+                size: Some(size.into()),
+                ..self.label
+            },
+            ..self
+        }
+    }
+
+    /// Sets the font of the text of the [`Tags`].
+    pub fn font(self, font: Font) -> Self {
+        Self {
+            label: Label {
+                font: Some(font),
+                ..self.label
+            },
+            ..self
+        }
+    }
+
+    /// Sets the style of the [`Tags`].
+    pub fn style(self, style: impl Into<<Theme as style::Catalog>::Style>) -> Self {
+        Self {
+            style: style.into(),
+            ..self
+        }
+    }
+}
+
+// The text of the chips, falling back to the defaults of the renderer
+#[derive(Debug, Clone, Copy, Default)]
+struct Label {
+    size: Option<Pixels>,
+    font: Option<Font>,
+}
+
+impl Label {
+    fn size<Renderer>(&self, renderer: &Renderer) -> Pixels
+    where
+        Renderer: text::Renderer,
+    {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn line_height<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer,
+    {
+        text::LineHeight::default()
+            .to_absolute(self.size(renderer))
+            .0
+    }
+
+    fn text<Renderer, Content>(&self, renderer: &Renderer, content: Content) -> Text<Content, Font>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.size(renderer),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            wrapping: text::Wrapping::None,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    fn chip_width<Renderer>(&self, renderer: &Renderer, content: &str) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Renderer::Paragraph::with_text(self.text(renderer, content)).min_width() + 2.0 * PADDING_X
+    }
+}
+
+fn draw_chip<Renderer>(
+    renderer: &mut Renderer,
+    label: &Label,
+    appearance: &iced::widget::container::Style,
+    text_color: Color,
+    bounds: Rectangle,
+    content: &str,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: appearance.border,
+            shadow: Default::default(),
+        },
+        appearance
+            .background
+            .unwrap_or_else(|| Color::TRANSPARENT.into()),
+    );
+
+    renderer.fill_text(
+        Text {
+            bounds: bounds.size(),
+            ..label.text(renderer, content.to_owned())
+        },
+        bounds.center(),
+        appearance.text_color.unwrap_or(text_color),
+        bounds,
+    );
+}
+
+impl<'a, Theme> Tags<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    fn overflow_label(&self, visible: usize) -> String {
+        format!("+{}", self.tags.len() - visible)
+    }
+
+    fn chips(&self, state: &State, bounds: Rectangle) -> Vec<(Rectangle, String)> {
+        let mut x = bounds.x;
+
+        let mut chips: Vec<_> = self
+            .tags
+            .iter()
+            .zip(&state.widths)
+            .take(state.visible)
+            .map(|(tag, width)| {
+                let chip =
+                    Rectangle::new(Point::new(x, bounds.y), Size::new(*width, bounds.height));
+                x += width + SPACING;
+
+                (chip, tag.clone())
+            })
+            .collect();
+
+        if state.visible < self.tags.len() {
+            let width = state.widths[self.tags.len()];
+
+            chips.push((
+                Rectangle::new(Point::new(x, bounds.y), Size::new(width, bounds.height)),
+                self.overflow_label(state.visible),
+            ));
+        }
+
+        chips
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Tags<'a, Theme>
+where
+    Theme: style::Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let max_width = limits.max().width;
+
+        state.widths = self
+            .tags
+            .iter()
+            .map(|tag| self.label.chip_width(renderer, tag))
+            .collect();
+
+        let mut visible = 0;
+        let mut width = 0.0;
+
+        for tag_width in &state.widths {
+            if width + tag_width > max_width {
+                break;
+            }
+
+            width += tag_width + SPACING;
+            visible += 1;
+        }
+
+        // Make room for the overflow chip by collapsing more tags into it
+        if visible < self.tags.len() {
+            loop {
+                let overflow = self
+                    .label
+                    .chip_width(renderer, &self.overflow_label(visible));
+                let used: f32 = state.widths[..visible].iter().map(|w| w + SPACING).sum();
+
+                if visible == 0 || used + overflow <= max_width {
+                    state.widths.push(overflow);
+                    break;
+                }
+
+                visible -= 1;
+            }
+        }
+
+        state.visible = visible;
+
+        let height = self.label.line_height(renderer) + 2.0 * PADDING_Y;
+
+        layout::Node::new(limits.resolve(
+            Length::Fill,
+            Length::Shrink,
+            Size::new(max_width, height),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let event::Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            let is_overflow_hovered = state.visible < self.tags.len()
+                && self
+                    .chips(state, layout.bounds())
+                    .last()
+                    .is_some_and(|(chip, _)| cursor.is_over(*chip));
+
+            if is_overflow_hovered != state.is_overflow_hovered {
+                state.is_overflow_hovered = is_overflow_hovered;
+                shell.request_redraw();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.tag(&self.style);
+
+        renderer.with_layer(bounds, |renderer| {
+            for (chip, content) in self.chips(state, bounds) {
+                draw_chip(
+                    renderer,
+                    &self.label,
+                    &appearance,
+                    style.text_color,
+                    chip,
+                    &content,
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.is_overflow_hovered {
+            return None;
+        }
+
+        let (chip, _) = self.chips(state, layout.bounds()).pop()?;
+
+        Some(overlay::Element::new(Box::new(Popover {
+            tags: &self.tags[state.visible..],
+            label: self.label,
+            style: &self.style,
+            position: Point::new(chip.x, chip.y + chip.height + SPACING) + translation,
+        })))
+    }
+}
+
+/// Lists the tags collapsed into the overflow chip.
+struct Popover<'a, Theme>
+where
+    Theme: style::Catalog,
+{
+    tags: &'a [String],
+    label: Label,
+    style: &'a <Theme as style::Catalog>::Style,
+    position: Point,
+}
+
+impl<'a, Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for Popover<'a, Theme>
+where
+    Theme: style::Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let width = self
+            .tags
+            .iter()
+            .map(|tag| self.label.chip_width(renderer, tag))
+            .fold(0.0, f32::max);
+        let line_height = self.label.line_height(renderer);
+
+        let size = Size::new(
+            width,
+            self.tags.len() as f32 * line_height + 2.0 * PADDING_Y,
+        );
+
+        // Keep the popover on screen
+        let position = Point::new(
+            self.position.x.min(bounds.width - size.width).max(0.0),
+            self.position.y.min(bounds.height - size.height).max(0.0),
+        );
+
+        layout::Node::new(size).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.tooltip(self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: Default::default(),
+            },
+            appearance
+                .background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+
+        let line_height = (bounds.height - 2.0 * PADDING_Y) / self.tags.len() as f32;
+
+        for (index, tag) in self.tags.iter().enumerate() {
+            let line = Rectangle {
+                y: bounds.y + PADDING_Y + index as f32 * line_height,
+                height: line_height,
+                ..bounds
+            };
+
+            renderer.fill_text(
+                Text {
+                    bounds: line.size(),
+                    ..self.label.text(renderer, tag.clone())
+                },
+                line.center(),
+                appearance.text_color.unwrap_or(style.text_color),
+                line,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tags<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: style::Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(tags: Tags<'a, Theme>) -> Self {
+        Element::new(tags)
+    }
+}
@@ -0,0 +1,68 @@
+//! Compute the messages a [`Table`] publishes as it's interacted with, so the way an
+//! application handles them can be tested without running it.
+//!
+//! Each helper goes through the same math as the widgets of the table, for the columns laid
+//! out within the given `width`.
+//!
+//! ```ignore
+//! let messages = test_utils::drag_divider(&table, 800.0, 0, 25.0, false);
+//!
+//! assert_eq!(messages, vec![Message::Resizing(0, 30.0), Message::Resized]);
+//! ```
+use iced::widget::{container, scrollable, text, text_input};
+
+use crate::style;
+use crate::table::{Column, Table};
+
+/// Returns the messages of dragging the divider of the [`Column`] at `index` by `offset`
+/// & releasing it: the start of the resize, if set, the last drag & the release.
+///
+/// `snap_to_columns` holds the modifier snapping the width to the other columns. Nothing is
+/// published for a column that can't be resized or isn't shown.
+pub fn drag_divider<'a, C, Row, Message, Theme, Renderer>(
+    table: &Table<'a, C, Row, Message, Theme, Renderer>,
+    width: f32,
+    index: usize,
+    offset: f32,
+    snap_to_columns: bool,
+) -> Vec<Message>
+where
+    Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+    Theme: style::Catalog
+        + container::Catalog
+        + scrollable::Catalog
+        + text::Catalog
+        + text_input::Catalog
+        + 'a,
+    <Theme as scrollable::Catalog>::Class<'a>: From<scrollable::StyleFn<'a, Theme>>,
+    C: Column<'a, Message, Theme, Renderer, Row = Row>,
+    Message: 'a + Clone,
+{
+    table.divider_drag(width, index, offset, snap_to_columns)
+}
+
+/// Returns the message of right-clicking the header of the [`Column`] at `header` & clicking
+/// the entry of the column at `index` in its context menu, if it can be toggled.
+///
+/// The column of the header is hidden from the menu itself, while the others are toggled from
+/// its submenu of columns.
+pub fn toggle_column<'a, C, Row, Message, Theme, Renderer>(
+    table: &Table<'a, C, Row, Message, Theme, Renderer>,
+    width: f32,
+    header: usize,
+    index: usize,
+) -> Option<Message>
+where
+    Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
+    Theme: style::Catalog
+        + container::Catalog
+        + scrollable::Catalog
+        + text::Catalog
+        + text_input::Catalog
+        + 'a,
+    <Theme as scrollable::Catalog>::Class<'a>: From<scrollable::StyleFn<'a, Theme>>,
+    C: Column<'a, Message, Theme, Renderer, Row = Row>,
+    Message: 'a + Clone,
+{
+    table.menu_toggle(width, header, index)
+}
@@ -0,0 +1,121 @@
+//! Save and restore named layouts of the columns of a [`Table`](crate::Table).
+use crate::columns::{ColumnLayout, Columns};
+use crate::table;
+
+/// A list of named column layouts, like the "saved views" of a data grid.
+///
+/// Enable the `serde` feature to persist [`Views`] between sessions.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Views {
+    views: Vec<View>,
+}
+
+/// A named snapshot of the order, widths & visibility of the columns.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct View {
+    /// The name of the view.
+    pub name: String,
+    /// The layout of each column, in order.
+    pub columns: Vec<ColumnLayout>,
+    /// The height the header was resized to, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub header_height: Option<f32>,
+}
+
+/// Messages to manage [`Views`], handled by [`Views::update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// Save the current layout under the given name, replacing any view with the same name.
+    Save(String),
+    /// Apply the view with the given name.
+    Apply(String),
+    /// Delete the view with the given name.
+    Delete(String),
+}
+
+impl Views {
+    /// Creates an empty list of [`Views`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Saves the current layout of `columns` as `name`, replacing any view with the same
+    /// name.
+    pub fn save<'a, T, Message, Theme, Renderer>(
+        &mut self,
+        name: impl Into<String>,
+        columns: &Columns<T>,
+    ) where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        let view = View {
+            name: name.into(),
+            columns: columns.layout(),
+            header_height: columns.header_height(),
+        };
+
+        match self.views.iter_mut().find(|v| v.name == view.name) {
+            Some(existing) => *existing = view,
+            None => self.views.push(view),
+        }
+    }
+
+    /// Applies the view named `name` to `columns`.
+    ///
+    /// Returns `false` if no such view exists.
+    pub fn apply<'a, T, Message, Theme, Renderer>(
+        &self,
+        name: &str,
+        columns: &mut Columns<T>,
+    ) -> bool
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        match self.get(name) {
+            Some(view) => {
+                columns.apply_layout(&view.columns);
+
+                if let Some(height) = view.header_height {
+                    columns.set_header_height(height);
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the view named `name`.
+    pub fn delete(&mut self, name: &str) {
+        self.views.retain(|view| view.name != name);
+    }
+
+    /// Returns the view named `name`.
+    pub fn get(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|view| view.name == name)
+    }
+
+    /// Returns an iterator over the saved views, in the order they were first saved.
+    pub fn iter(&self) -> impl Iterator<Item = &View> {
+        self.views.iter()
+    }
+
+    /// Handles a [`Message`] against `columns`.
+    pub fn update<'a, T, Msg, Theme, Renderer>(
+        &mut self,
+        message: Message,
+        columns: &mut Columns<T>,
+    ) where
+        T: table::Column<'a, Msg, Theme, Renderer>,
+    {
+        match message {
+            Message::Save(name) => self.save(name, columns),
+            Message::Apply(name) => {
+                self.apply(&name, columns);
+            }
+            Message::Delete(name) => self.delete(&name),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Persist the widths of the columns of a [`Table`](crate::Table).
+use std::collections::BTreeMap;
+
+use crate::columns::Columns;
+use crate::table;
+
+/// The widths of columns, keyed by their [`id`](table::Column::id).
+///
+/// Since widths are looked up by id rather than position, a [`WidthStore`] keeps matching
+/// the right columns after they are reordered, hidden or added. Enable the `serde` feature
+/// to persist it between sessions.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WidthStore {
+    widths: BTreeMap<String, f32>,
+}
+
+impl WidthStore {
+    /// Creates an empty [`WidthStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the stored width of the column with the given id.
+    pub fn get(&self, id: &str) -> Option<f32> {
+        self.widths.get(id).copied()
+    }
+
+    /// Stores the width of the column with the given id.
+    pub fn set(&mut self, id: impl Into<String>, width: f32) {
+        self.widths.insert(id.into(), width);
+    }
+
+    /// Stores the current width of every column.
+    ///
+    /// Widths of columns that aren't part of `columns` are kept, so optional columns don't
+    /// lose their width while they are absent.
+    pub fn save<'a, C, Message, Theme, Renderer>(&mut self, columns: &[C])
+    where
+        C: table::Column<'a, Message, Theme, Renderer>,
+    {
+        for (index, column) in columns.iter().enumerate() {
+            self.set(column.id(index), column.width());
+        }
+    }
+
+    /// Applies the stored widths to the matching columns.
+    ///
+    /// Columns without a stored width are left untouched.
+    pub fn apply<'a, T, Message, Theme, Renderer>(&self, columns: &mut Columns<T>)
+    where
+        T: table::Column<'a, Message, Theme, Renderer>,
+    {
+        let widths: Vec<_> = columns
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((index, self.get(&entry.column().id(index))?)))
+            .collect();
+
+        for (index, width) in widths {
+            columns.set_width(index, width);
+        }
+    }
+}